@@ -1,12 +1,10 @@
-use shrewnit::prelude::*;
+use shrewnit::Dimension;
 
 shrewnit::dimension!(
     Current {
         canonical: Amperes,
 
         Amperes: 1.0 per canonical,
-    } where {
-        Self * Voltage => Power in Watts,
     }
 );
 
@@ -16,7 +14,8 @@ shrewnit::dimension!(
 
         Volts: 1.0 per canonical,
     } where {
-        Self * Current => Power in Watts,
+        // Also implements `Current * Voltage`, `Power / Voltage` and `Power / Current`.
+        Self * Current <=> Power in Watts,
     }
 );
 
@@ -25,9 +24,6 @@ shrewnit::dimension!(
         canonical: Watts,
 
         Watts: 1.0 per canonical,
-    } where {
-        Self / Voltage => Current in Amperes,
-        Self / Current => Voltage in Volts,
     }
 );
 
@@ -37,5 +33,6 @@ fn main() {
 
     let power: Power = current * voltage;
 
-    println!("{}", power.to::<Watts>())
+    println!("{}", Dimension::to::<Watts>(&power));
+    println!("{}", Dimension::to::<Amperes>(&(power / voltage)));
 }
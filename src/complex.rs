@@ -0,0 +1,37 @@
+//! Complex scalars from the [`num-complex`](https://docs.rs/num-complex) crate, enabled by the `complex` feature.
+//!
+//! [`Complex`](num_complex::Complex) numbers are [`Scalar`](crate::Scalar)s, so quantities can be phasors
+//! and work with the relations between dimensions, like voltage times current giving power.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use num_complex::Complex;
+//!
+//! let voltage = Complex::new(3.0f64, 4.0) * Volts;
+//! let current = Complex::new(2.0f64, 0.0) * Amperes;
+//!
+//! let power: Power<Complex<f64>> = voltage * current;
+//! assert_eq!(power.to::<Watts>(), Complex::new(6.0, 8.0));
+//! assert_eq!(voltage.to::<Millivolts>(), Complex::new(3000.0, 4000.0));
+//! ```
+//!
+//! With the `std` or `libm` feature, every dimension with a complex scalar can be built from a magnitude and an [`Angle`](crate::Angle),
+//! and split back into them.
+#![cfg_attr(
+    any(feature = "std", feature = "libm"),
+    doc = r#"
+```
+# use shrewnit::prelude::*;
+use num_complex::Complex;
+
+let voltage = Voltage::from_polar(230.0 * Volts, 0.0 * Degrees);
+let current = Current::from_polar(10.0 * Amperes, -30.0 * Degrees);
+
+// Complex power is the voltage times the conjugate of the current.
+let power: Power<Complex<f64>> = voltage * current.conj();
+assert!((power.magnitude().to::<Watts>() - 2300.0).abs() < 1e-9);
+assert!((power.phase().to::<Degrees>() - 30.0).abs() < 1e-9);
+assert!((power.re().to::<Watts>() - 1991.858).abs() < 0.001);
+```
+"#
+)]
@@ -0,0 +1,318 @@
+//! Quantities written in several units, like `5 ft 3 in` or `1 h 23 min 4 s`.
+//!
+//! A tuple of units, from largest to smallest, implements [`CompoundUnits`].
+//! [`Dimension::to_compound`](crate::Dimension::to_compound) splits a quantity into a whole number of each unit
+//! and a remainder in the last unit, and [`Dimension::display_compound`](crate::Dimension::display_compound) formats it.
+//! Parsing a quantity accepts any number of parts, which are added together.
+//!
+//! ```
+//! use shrewnit::prelude::*;
+//!
+//! let height = 63.0f64 * Inches;
+//! let (feet, inches) = height.to_compound((Feet, Inches));
+//! assert_eq!(feet, 5.0);
+//! assert!((inches - 3.0).abs() < 1e-9);
+//!
+//! assert_eq!(height.display_compound((Feet, Inches)).to_string(), "5 ft 3 in");
+//!
+//! let time = 4984.0f64 * Seconds;
+//! assert_eq!(format!("{}", time.display_compound((Hours, Minutes, Seconds))), "1 h 23 min 4 s");
+//! assert_eq!(format!("{:#}", (6.0f64 * Stones).display_compound((Stones, Pounds))), "6 stones");
+//!
+//! let parsed: Length = "5 ft 3 in".parse().unwrap();
+//! assert!((parsed.to::<Inches>() - 63.0).abs() < 1e-9);
+//! ```
+//!
+//! A negative quantity is written with a single leading sign, which applies to every part when parsing.
+//! Signs on the other parts are rejected, since `-5 ft -3 in` could mean either `-5 ft 3 in` or `-4 ft 9 in`.
+//!
+//! ```
+//! use shrewnit::prelude::*;
+//!
+//! let depth = -63.0f64 * Inches;
+//! let text = depth.display_compound((Feet, Inches)).to_string();
+//! assert_eq!(text, "-5 ft 3 in");
+//! assert!((text.parse::<Length>().unwrap().to::<Inches>() + 63.0).abs() < 1e-9);
+//!
+//! let loss = -100.0f64 * Ounces;
+//! let text = loss.display_compound((Pounds, Ounces)).to_string();
+//! assert_eq!(text, "-6 lb 4 oz");
+//! assert!((text.parse::<Mass>().unwrap().to::<Ounces>() + 100.0).abs() < 1e-9);
+//!
+//! let offset = -4984.0f64 * Seconds;
+//! let text = offset.display_compound((Hours, Minutes, Seconds)).to_string();
+//! assert_eq!(text, "-1 h 23 min 4 s");
+//! assert!((text.parse::<Time>().unwrap().to::<Seconds>() + 4984.0).abs() < 1e-9);
+//! ```
+//!
+//! The last part is rounded to the precision of the formatter, or to a whole number if none is given.
+//! Parts that round up to a whole larger unit carry over, so a quantity is never shown as `5 ft 12 in`.
+//!
+//! ```
+//! use shrewnit::prelude::*;
+//!
+//! let height = 71.9f64 * Inches;
+//! assert_eq!(height.display_compound((Feet, Inches)).to_string(), "6 ft");
+//! assert_eq!(format!("{:.1}", height.display_compound((Feet, Inches))), "5 ft 11.9 in");
+//! ```
+
+use core::{
+    fmt::{self, Write},
+    marker::PhantomData,
+};
+
+use crate::{
+    display::{write_padded, Measure},
+    units::{ConversionError, UnitDescriptor, UnitInfo},
+    Dimension, Scalar, UnitOf,
+};
+
+/// The largest number of units a compound quantity can be split into.
+const MAX_UNITS: usize = 6;
+
+/// A list of units that a quantity can be split between, from largest to smallest.
+///
+/// This is implemented for tuples of two to six units of the same dimension.
+pub trait CompoundUnits<S: Scalar, D: Dimension<S>> {
+    /// The parts of a quantity, one scalar for each unit.
+    type Parts;
+    /// The number of units.
+    const LEN: usize;
+
+    /// Describes the unit at `index`, along with the amount of canonical units in one of it.
+    fn unit(index: usize) -> (UnitDescriptor, f64);
+    /// Collects one scalar for each unit into [`Self::Parts`](CompoundUnits::Parts).
+    fn parts<F: FnMut(usize) -> S>(part: F) -> Self::Parts;
+}
+
+/// Converts a scalar to an `f64`, or NaN if it can't be.
+fn to_f64<S: Scalar>(value: S) -> f64 {
+    value.to_f64().unwrap_or(core::f64::NAN)
+}
+
+/// Finds the amount of canonical units in one of `U`.
+fn factor<S: Scalar, D: Dimension<S>, U: UnitOf<S, D> + UnitInfo>() -> f64 {
+    U::FACTOR.unwrap_or_else(|| match (S::from_f64(1.0), S::from_f64(0.0)) {
+        (Some(one), Some(zero)) => to_f64(U::to_canonical(one)) - to_f64(U::to_canonical(zero)),
+        _ => core::f64::NAN,
+    })
+}
+
+macro_rules! compound_units_imp {
+    ($len:expr; $($unit:ident $index:tt),+) => {
+        impl<S: Scalar, D: Dimension<S>, $($unit: UnitOf<S, D> + UnitInfo),+> CompoundUnits<S, D> for ($($unit,)+) {
+            type Parts = ($(compound_units_imp!(@scalar $unit),)+);
+            const LEN: usize = $len;
+
+            #[inline]
+            fn unit(index: usize) -> (UnitDescriptor, f64) {
+                let units = [$((UnitDescriptor::of::<$unit>(), factor::<S, D, $unit>())),+];
+                units[index]
+            }
+            #[inline]
+            fn parts<Part: FnMut(usize) -> S>(mut part: Part) -> Self::Parts {
+                ($(part($index),)+)
+            }
+        }
+    };
+    (@scalar $unit:ident) => {
+        S
+    };
+}
+
+compound_units_imp!(2; A 0, B 1);
+compound_units_imp!(3; A 0, B 1, C 2);
+compound_units_imp!(4; A 0, B 1, C 2, E 3);
+compound_units_imp!(5; A 0, B 1, C 2, E 3, F 4);
+compound_units_imp!(6; A 0, B 1, C 2, E 3, F 4, G 5);
+
+/// Lists the amount of canonical units in one of each unit of `U`.
+fn factors<S: Scalar, D: Dimension<S>, U: CompoundUnits<S, D>>() -> [f64; MAX_UNITS] {
+    let mut factors = [0.0; MAX_UNITS];
+    for (index, factor) in factors.iter_mut().enumerate().take(U::LEN) {
+        *factor = U::unit(index).1;
+    }
+    factors
+}
+
+/// Splits the magnitude of `canonical` into whole numbers of every unit but the last.
+///
+/// The last part is rounded to `precision` decimal places, if given.
+/// Returns `true` if `canonical` is negative.
+fn decompose(canonical: f64, factors: &[f64], precision: Option<usize>, parts: &mut [f64]) -> bool {
+    let negative = canonical < 0.0;
+    let mut rest = if negative { -canonical } else { canonical };
+
+    let last = factors.len() - 1;
+    for (part, factor) in parts.iter_mut().zip(factors).take(last) {
+        *part = trunc(rest / factor);
+        rest = (rest - *part * factor).max(0.0);
+    }
+    parts[last] = round(rest / factors[last], precision);
+
+    // Carry parts that round up to a whole larger unit, allowing for rounding errors in the factors.
+    for index in (1..=last).rev() {
+        let ratio = round(
+            factors[index - 1] / factors[index],
+            if index == last { precision } else { Some(0) },
+        );
+        if parts[index] >= ratio * (1.0 - 1e-9) {
+            parts[index] = (parts[index] - ratio).max(0.0);
+            parts[index - 1] += 1.0;
+        }
+    }
+    negative
+}
+
+/// Splits a quantity into a whole number of every unit but the last, and a remainder in the last.
+///
+/// Used by [`Dimension::try_to_compound`](crate::Dimension::try_to_compound).
+#[doc(hidden)]
+pub fn __to_compound<S: Scalar, D: Dimension<S>, U: CompoundUnits<S, D>>(
+    quantity: &D,
+) -> Result<U::Parts, ConversionError> {
+    let canonical = to_f64(quantity.canonical());
+    if canonical.is_nan() {
+        return Err(ConversionError::NaN);
+    }
+    let factors = factors::<S, D, U>();
+    let mut parts = [0.0; MAX_UNITS];
+    let negative = decompose(canonical, &factors[..U::LEN], None, &mut parts[..U::LEN]);
+
+    let mut fits = true;
+    let parts = U::parts(|index| {
+        let part = if negative {
+            -parts[index]
+        } else {
+            parts[index]
+        };
+        S::from_f64(part).unwrap_or_else(|| {
+            fits = false;
+            quantity.canonical()
+        })
+    });
+    if fits {
+        Ok(parts)
+    } else {
+        Err(ConversionError::Overflow)
+    }
+}
+
+/// Displays a quantity split between several units.
+///
+/// Created by [`Dimension::display_compound`](crate::Dimension::display_compound).
+/// Parts that are zero are left out, unless the whole quantity is zero.
+#[derive(Clone, Copy)]
+pub struct DisplayCompound<S: Scalar, D, U> {
+    canonical: S,
+    units: PhantomData<(D, U)>,
+}
+
+impl<S: Scalar, D, U> DisplayCompound<S, D, U> {
+    /// Displays a value in the canonical unit of `D`.
+    #[inline]
+    pub fn new(canonical: S) -> Self {
+        Self {
+            canonical,
+            units: PhantomData,
+        }
+    }
+}
+
+impl<S: Scalar, D: Dimension<S>, U: CompoundUnits<S, D>> fmt::Display for DisplayCompound<S, D, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let factors = factors::<S, D, U>();
+        let precision = f.precision().unwrap_or(0);
+        let mut parts = [0.0; MAX_UNITS];
+        let negative = decompose(
+            to_f64(self.canonical.clone()),
+            &factors[..U::LEN],
+            Some(precision),
+            &mut parts[..U::LEN],
+        );
+
+        let compound = Compound {
+            negative,
+            sign_plus: f.sign_plus(),
+            alternate: f.alternate(),
+            precision,
+            parts: &parts[..U::LEN],
+            unit: U::unit,
+        };
+        let mut measure = Measure::default();
+        compound.write(&mut measure)?;
+        write_padded(f, measure.len, |f| compound.write(f))
+    }
+}
+
+/// A decomposed quantity, ready to be written.
+struct Compound<'a> {
+    negative: bool,
+    sign_plus: bool,
+    alternate: bool,
+    precision: usize,
+    parts: &'a [f64],
+    unit: fn(usize) -> (UnitDescriptor, f64),
+}
+
+impl Compound<'_> {
+    fn write<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let last = self.parts.len() - 1;
+        let is_zero = self.parts.iter().all(|part| *part == 0.0);
+
+        if self.negative && !is_zero {
+            w.write_char('-')?;
+        } else if self.sign_plus {
+            w.write_char('+')?;
+        }
+
+        let mut first = true;
+        for (index, part) in self.parts.iter().enumerate() {
+            if *part == 0.0 && !(is_zero && index == last) {
+                continue;
+            }
+            if !first {
+                w.write_char(' ')?;
+            }
+            first = false;
+
+            let precision = if index == last { self.precision } else { 0 };
+            let mut measure = Measure::default();
+            write!(measure, "{:.*}", precision, part)?;
+            write!(w, "{:.*}", precision, part)?;
+
+            let (unit, _) = (self.unit)(index);
+            let unit = match (self.alternate, measure.is_one()) {
+                (false, _) => unit.symbol,
+                (true, true) => unit.name,
+                (true, false) => unit.plural,
+            };
+            if !unit.is_empty() {
+                w.write_char(' ')?;
+                w.write_str(unit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rounds a non-negative value down.
+fn trunc(value: f64) -> f64 {
+    // Every float at least this large is already a whole number.
+    if value < 4_503_599_627_370_496.0 {
+        value as i64 as f64
+    } else {
+        value
+    }
+}
+
+/// Rounds a non-negative value to the given number of decimal places, with halves rounded up.
+fn round(value: f64, precision: Option<usize>) -> f64 {
+    match precision {
+        Some(precision) => {
+            let scale = (0..precision).fold(1.0, |scale, _| scale * 10.0);
+            trunc(value * scale + 0.5) / scale
+        }
+        None => value,
+    }
+}
@@ -0,0 +1,52 @@
+//! Decimal scalars from the [`rust_decimal`](https://docs.rs/rust_decimal) crate, enabled by the `decimal` feature.
+//!
+//! [`Decimal`](rust_decimal::Decimal) is a [`Scalar`](crate::Scalar).
+//! Conversion factors are applied as the decimals they're written as, like `0.3048` for feet,
+//! so conversions and sums don't drift the way they do with floats.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use rust_decimal::Decimal;
+//!
+//! let reading = Decimal::new(15, 1) * WattHours;
+//! assert_eq!(reading.to::<Joules>(), Decimal::new(5400, 0));
+//!
+//! let total: Energy<Decimal> = (0..10_000).map(|_| Decimal::new(1, 1) * WattHours).sum();
+//! assert_eq!(total.to::<WattHours>(), Decimal::new(1000, 0));
+//!
+//! let length = Decimal::new(1, 0) * Feet;
+//! assert_eq!(length.to::<Meters>(), Decimal::new(3048, 4));
+//! ```
+//!
+//! Results that aren't exact decimals, like a third of a meter, are rounded to the 28 digits a `Decimal` holds.
+//! Parsed quantities keep every digit they're written with.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use rust_decimal::Decimal;
+//!
+//! let reading: Energy<Decimal> = "1234567.890123456789 J".parse().unwrap();
+//! assert_eq!(reading.to::<Joules>(), Decimal::new(1234567890123456789, 12));
+//! ```
+//!
+//! Imperial areas, volumes and masses are defined by exact metric factors, and Fahrenheit by its exact offset
+//! from absolute zero, so they convert without rounding too.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use rust_decimal::Decimal;
+//!
+//! let tank = Decimal::new(12, 0) * Gallons;
+//! assert_eq!(tank.to::<CubicInches>(), Decimal::new(2772, 0));
+//!
+//! let floor = Decimal::new(144, 0) * SquareInches;
+//! assert_eq!(floor.to::<SquareFeet>(), Decimal::new(1, 0));
+//!
+//! let parcel = Decimal::new(3, 0) * Pounds;
+//! assert_eq!(parcel.to::<Ounces>(), Decimal::new(48, 0));
+//!
+//! let boiling = Decimal::new(212, 0) * Fahrenheit;
+//! assert_eq!(boiling.to::<Celsius>(), Decimal::new(100, 0));
+//! assert_eq!(boiling.to::<Fahrenheit>(), Decimal::new(212, 0));
+//! assert_eq!((Decimal::new(-40, 0) * Celsius).to::<Fahrenheit>(), Decimal::new(-40, 0));
+//! ```
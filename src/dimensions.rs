@@ -16,14 +16,90 @@
 //! - [`Mass`]
 //! - [`Torque`]
 //! - [`Energy`]
+//! - [`SpecificEnergy`]
 //! - [`Power`]
 //! - [`Voltage`]
 //! - [`Current`]
 //! - [`Temperature`]
+//! - [`TemperatureInterval`]
+//! - [`Ratio`]
 //!
 //! If you need to define custom dimensions, you can use the [`dimension!`] macro.
 
-use crate::{dimension, unit_type, Scalar, UnitOf};
+use crate::{
+    dimension,
+    exponents::{Dimensioned, NamedDimension},
+    units::UnitTable,
+    Scalar,
+};
+
+/// Registers dimensions as the [`NamedDimension`] of their exponents.
+macro_rules! named_dimensions {
+    ($($dimension:ident),* $(,)?) => {
+        $(
+            impl<S: Scalar> NamedDimension<S> for <$dimension<S> as Dimensioned>::Exponents {
+                type Dimension = $dimension<S>;
+            }
+        )*
+    };
+}
+
+named_dimensions!(
+    Length,
+    Area,
+    Volume,
+    Time,
+    LinearVelocity,
+    LinearAcceleration,
+    Angle,
+    AngularVelocity,
+    AngularAcceleration,
+    Mass,
+    Force,
+    Pressure,
+    Torque,
+    Energy,
+    SpecificEnergy,
+    Power,
+    Voltage,
+    Current,
+    TemperatureInterval,
+    Ratio,
+);
+
+/// Lists the units of dimensions provided by Shrewnit.
+macro_rules! builtin_units {
+    ($($dimension:ident),* $(,)?) => {
+        /// The units of every dimension provided by Shrewnit.
+        ///
+        /// This is used to tell units of the wrong dimension apart from unknown units, and to convert between units by name.
+        pub(crate) const BUILTIN_UNITS: &[UnitTable] = &[$(<$dimension<f64>>::UNIT_TABLE),*];
+    };
+}
+
+builtin_units!(
+    Length,
+    Area,
+    Volume,
+    Time,
+    LinearVelocity,
+    LinearAcceleration,
+    Angle,
+    AngularVelocity,
+    AngularAcceleration,
+    Mass,
+    Force,
+    Pressure,
+    Torque,
+    Energy,
+    SpecificEnergy,
+    Power,
+    Voltage,
+    Current,
+    Temperature,
+    TemperatureInterval,
+    Ratio,
+);
 
 dimension!(
     /// Represents a distance.
@@ -31,100 +107,97 @@ dimension!(
     /// Canonically represented in meters.
     pub Length {
         canonical: Meters,
+        exponents: [length: 1],
 
         /// Represents the millimeter unit of length.
-        Millimeters: 1000.0 per canonical,
+        Millimeters ["mm", "millimeter", "millimeters", "millimetre", "millimetres"] in metric: 1000.0 per canonical,
         /// Represents the centimeter unit of length.
-        Centimeters: 100.0 per canonical,
+        Centimeters ["cm", "centimeter", "centimeters", "centimetre", "centimetres"] in metric: 100.0 per canonical,
         /// Represents the meter unit of length.
         /// This is the standard SI unit of length.
-        Meters: 1.0 per canonical,
+        Meters ["m", "meter", "meters", "metre", "metres"] in metric: 1.0 per canonical,
         /// Represents the kilometer unit of length.
-        Kilometers: per 1000.0 canonical,
+        Kilometers ["km", "kilometer", "kilometers", "kilometre", "kilometres"] in metric: per 1000.0 canonical,
 
         /// Represents the inch unit of length.
-        Inches: per 0.0254 canonical,
+        Inches ["in", "inch", "inches"] in imperial: per 0.0254 canonical,
         /// Represents the foot unit of length.
-        Feet: per 0.3048 canonical,
+        Feet ["ft", "foot", "feet"] in imperial: per 0.3048 canonical,
         /// Represents the yard unit of length.
-        Yards: per 0.9144 canonical,
+        Yards ["yd", "yard", "yards"] in imperial: per 0.9144 canonical,
         /// Represents the mile unit of length.
-        Miles: per 1609.344 canonical,
+        Miles ["mi", "mile", "miles"] in imperial: per 1609.344 canonical,
         /// Represents the nautical mile unit of length.
-        NauticalMiles: per 1852.0 canonical,
+        NauticalMiles ["nmi", "nautical mile", "nautical miles"]: per 1852.0 canonical,
     } where {
-        Self / Time => LinearVelocity in MetersPerSecond,
-        Self * Force => Energy in Joules,
-        Self * Length => Area in SquareMeters,
-        Self * Area => Volume in CubicMeters,
+        Self / Time <=> LinearVelocity in MetersPerSecond,
+        Self * Self <=> Area in SquareMeters,
+        Self * Area <=> Volume in CubicMeters,
+        Self * Force <=> Energy in Joules,
     }
 );
 
 dimension!(
     pub Area {
         canonical: SquareMeters,
+        exponents: [length: 2],
 
         /// Represents the square millimeter unit of area.
-        SquareMillimeters: 1_000_000.0 per canonical,
+        SquareMillimeters ["mm²", "square millimeter", "square millimeters", "mm^2", "mm2"] in metric: 1_000_000.0 per canonical,
         /// Represents the square centimeter unit of area.
-        SquareCentimeters: 10_000.0 per canonical,
+        SquareCentimeters ["cm²", "square centimeter", "square centimeters", "cm^2", "cm2"] in metric: 10_000.0 per canonical,
         /// Represents the square meter unit of area.
         /// This is the standard SI unit of area.
-        SquareMeters: 1.0 per canonical,
+        SquareMeters ["m²", "square meter", "square meters", "m^2", "m2"] in metric: 1.0 per canonical,
         /// Represents the square kilometer unit of area.
-        SquareKilometers: per 1_000_000.0 canonical,
+        SquareKilometers ["km²", "square kilometer", "square kilometers", "km^2", "km2"] in metric: per 1_000_000.0 canonical,
 
         /// Represents the square inch unit of area.
-        SquareInches: 1550.0031000062 per canonical,
+        SquareInches ["in²", "square inch", "square inches", "in^2", "sq in"] in imperial: per 0.00064516 canonical,
         /// Represents the square foot unit of area.
-        SquareFeet: 10.7639104167097 per canonical,
+        SquareFeet ["ft²", "square foot", "square feet", "ft^2", "sq ft"] in imperial: per 0.09290304 canonical,
         /// Represents the square yard unit of area.
-        SquareYards: per 0.83612736 canonical,
+        SquareYards ["yd²", "square yard", "square yards", "yd^2", "sq yd"] in imperial: per 0.83612736 canonical,
         /// Represents the acre unit of area.
-        Acres: per 4046.8564224 canonical,
-    } where {
-        Self / Length => Length in Meters,
-        Self * Length => Volume in CubicMeters,
+        Acres ["ac", "acre", "acres"] in imperial: per 4046.8564224 canonical,
     }
 );
 
 dimension!(
     pub Volume {
         canonical: CubicMeters,
+        exponents: [length: 3],
 
         /// Represents the cubic millimeter unit of volume.
-        Milliliters: 1_000_000.0 per canonical,
+        Milliliters ["mL", "milliliter", "milliliters", "ml", "millilitre", "millilitres"] in metric: 1_000_000.0 per canonical,
         /// Represents the cubic centimeter unit of volume.
-        Liters: 1000.0 per canonical,
+        Liters ["L", "liter", "liters", "l", "litre", "litres"] in metric: 1000.0 per canonical,
 
         /// Represents the cubic meter unit of volume.
-        CubicMillimeters: 1_000_000_000.0 per canonical,
+        CubicMillimeters ["mm³", "cubic millimeter", "cubic millimeters", "mm^3", "mm3"] in metric: 1_000_000_000.0 per canonical,
         /// Represents the cubic meter unit of volume.
-        CubicCentimeters: 1_000_000.0 per canonical,
+        CubicCentimeters ["cm³", "cubic centimeter", "cubic centimeters", "cm^3", "cm3", "cc"]: 1_000_000.0 per canonical,
         /// Represents the cubic meter unit of volume.
-        CubicMeters: 1.0 per canonical,
+        CubicMeters ["m³", "cubic meter", "cubic meters", "m^3", "m3"] in metric: 1.0 per canonical,
         /// Represents the cubic kilometer unit of volume.
-        CubicKilometers: per 1_000_000_000.0 canonical,
+        CubicKilometers ["km³", "cubic kilometer", "cubic kilometers", "km^3", "km3"] in metric: per 1_000_000_000.0 canonical,
 
         /// Represents the cubic inch unit of volume.
-        CubicInches: 61023.7440947323 per canonical,
+        CubicInches ["in³", "cubic inch", "cubic inches", "in^3", "cu in"] in imperial: per 0.000016387064 canonical,
         /// Represents the cubic foot unit of volume.
-        CubicFeet: 35.3146667214886 per canonical,
+        CubicFeet ["ft³", "cubic foot", "cubic feet", "ft^3", "cu ft"] in imperial: per 0.028316846592 canonical,
         /// Represents the cubic yard unit of volume.
-        CubicYards: 1.30795061931439 per canonical,
+        CubicYards ["yd³", "cubic yard", "cubic yards", "yd^3", "cu yd"] in imperial: per 0.764554857984 canonical,
 
         /// Represents the fluid ounce unit of volume.
-        FluidOunces: 33814.022701843 per canonical,
+        FluidOunces ["fl oz", "fluid ounce", "fluid ounces"] in imperial: per 0.0000295735295625 canonical,
         /// Represents the pint unit of volume.
-        Pints: 2113.37641886519 per canonical,
+        Pints ["pt", "pint", "pints"] in imperial: per 0.000473176473 canonical,
         /// Represents the quart unit of volume.
-        Quarts: 1056.68820943259 per canonical,
+        Quarts ["qt", "quart", "quarts"] in imperial: per 0.000946352946 canonical,
         /// Represents the gallon unit of volume.
-        Gallons: 264.172052358148 per canonical,
+        Gallons ["gal", "gallon", "gallons"] in imperial: per 0.003785411784 canonical,
 
-    } where {
-        Self / Length => Area in SquareMeters,
-        Self / Area => Length in Meters,
     }
 );
 
@@ -134,117 +207,124 @@ dimension!(
     /// Canonically represented in seconds.
     pub Time {
         canonical: Seconds,
+        exponents: [time: 1],
 
         /// Represents the millisecond unit of time.
-        Microseconds: 1_000_000.0 per canonical,
+        Microseconds ["µs", "microsecond", "microseconds", "us"] in metric, imperial: 1_000_000.0 per canonical,
         /// Represents the millisecond unit of time.
-        Milliseconds: 1000.0 per canonical,
+        Milliseconds ["ms", "millisecond", "milliseconds"] in metric, imperial: 1000.0 per canonical,
 
         /// Represents the second unit of time.
         /// This is the standard SI unit of time.
-        Seconds: 1.0 per canonical,
+        Seconds ["s", "second", "seconds", "sec", "secs"] in metric, imperial: 1.0 per canonical,
         /// Represents the minute unit of time.
-        Minutes: per 60.0 canonical,
+        Minutes ["min", "minute", "minutes", "mins"] in metric, imperial: per 60.0 canonical,
         /// Represents the hour unit of time.
-        Hours: per 3600.0 canonical,
+        Hours ["h", "hour", "hours", "hr", "hrs"] in metric, imperial: per 3600.0 canonical,
 
         /// Represents the day unit of time.
         /// 86400 seconds.
-        Days: per 86_400.0 canonical,
+        Days ["d", "day", "days"] in metric, imperial: per 86_400.0 canonical,
         /// Represents the week unit of time.
         /// 604800 seconds.
-        Weeks: per 604_800.0 canonical,
+        Weeks ["wk", "week", "weeks"] in metric, imperial: per 604_800.0 canonical,
         /// Represents the year unit of time.
         /// 31536000 seconds.
-        Years: per 31_556_926.0 canonical,
-    } where {
-        Self * LinearVelocity => Length in Meters,
-        Self * LinearAcceleration => LinearVelocity in MetersPerSecond,
-        Self * AngularVelocity => Angle in Radians,
-        Self * AngularAcceleration => AngularVelocity in RadiansPerSecond,
+        Years ["yr", "year", "years"] in metric, imperial: per 31_556_926.0 canonical,
     }
 );
 
 dimension!(
+    /// Represents linear velocity.
+    ///
+    /// Canonically represented in meters per second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// # fn close(a: f64, b: f64) -> bool { (a - b).abs() < 1e-9 }
+    /// assert!(close((1.0f64 * FeetPerSecond).to::<MetersPerSecond>(), 0.3048));
+    /// assert!(close((88.0f64 * FeetPerSecond).to::<MilesPerHour>(), 60.0));
+    /// ```
     pub LinearVelocity {
         canonical: MetersPerSecond,
+        exponents: [length: 1, time: -1],
 
         /// Represents the meter per second unit of linear velocity.
-        MetersPerSecond: 1.0 per canonical,
+        MetersPerSecond ["m/s", "meter per second", "meters per second"] in metric: 1.0 per canonical,
         /// Represents the kilometer per second unit of linear velocity.
-        KilometersPerSecond: per 1000.0 canonical,
+        KilometersPerSecond ["km/s", "kilometer per second", "kilometers per second"] in metric: per 1000.0 canonical,
         /// Represents the kilometer per hour unit of linear velocity.
-        KilometersPerHour: 3.6 per canonical,
+        KilometersPerHour ["km/h", "kilometer per hour", "kilometers per hour", "kph"] in metric: 3.6 per canonical,
         /// Represents the foot per second unit of linear velocity.
-        FeetPerSecond: 3.281 per canonical,
+        FeetPerSecond ["ft/s", "foot per second", "feet per second", "fps"] in imperial: per 0.3048 canonical,
         /// Represents the mile per hour unit of linear velocity.
-        MilesPerHour: per 0.44704 canonical,
+        MilesPerHour ["mph", "mile per hour", "miles per hour", "mi/h"] in imperial: per 0.44704 canonical,
     } where {
-        Self * Time => Length in Meters,
-        Self / Time => LinearAcceleration in MetersPerSecondSquared,
+        Self / Time <=> LinearAcceleration in MetersPerSecondSquared,
     }
 );
 dimension! {
     pub LinearAcceleration {
         canonical: MetersPerSecondSquared,
+        exponents: [length: 1, time: -2],
 
         /// Represents the meter per second squared unit of linear acceleration.
-        MetersPerSecondSquared: 1.0 per canonical,
+        MetersPerSecondSquared ["m/s²", "meter per second squared", "meters per second squared", "m/s^2"] in metric: 1.0 per canonical,
         /// Represents the foot per second squared unit of linear acceleration.
-        FeetPerSecondSquared: per 0.3048 canonical,
-    } where {
-        Self * Time => LinearVelocity in MetersPerSecond,
-        Self * Mass => Force in Newtons,
+        FeetPerSecondSquared ["ft/s²", "foot per second squared", "feet per second squared", "ft/s^2"] in imperial: per 0.3048 canonical,
     }
 }
 
 dimension!(
     pub Angle {
         canonical: Radians,
+        exponents: [angle: 1],
 
         /// Represents the radian unit of angle.
-        Radians: 1.0 per canonical,
+        Radians ["rad", "radian", "radians"] in metric, imperial: 1.0 per canonical,
         /// Represents the degree unit of angle.
-        Rotations: per 6.28318530717959 canonical,
+        Rotations ["rot", "rotation", "rotations", "rev", "turn", "turns"] in metric, imperial: per 6.28318530717959 canonical,
         /// Represents the degree unit of angle.
-        Degrees: 57.2957795130823 per canonical,
+        Degrees ["°", "degree", "degrees", "deg"] in metric, imperial: 57.2957795130823 per canonical,
         /// Represents the gradian unit of angle.
-        Gradians: 63.6619772367581 per canonical,
+        Gradians ["grad", "gradian", "gradians", "gon"]: 63.6619772367581 per canonical,
     } where {
-        Self / Time => AngularVelocity in RadiansPerSecond,
+        Self / Time <=> AngularVelocity in RadiansPerSecond,
     }
 );
 dimension!(
     pub AngularVelocity {
         canonical: RadiansPerSecond,
+        exponents: [angle: 1, time: -1],
 
         /// Represents the radian per second unit of angular velocity.
-        RadiansPerSecond: 1.0 per canonical,
+        RadiansPerSecond ["rad/s", "radian per second", "radians per second"] in metric, imperial: 1.0 per canonical,
         /// Represents the rotation per second unit of angular velocity.
-        RotationsPerSecond: per 6.28318530717959 canonical,
+        RotationsPerSecond ["rot/s", "rotation per second", "rotations per second", "rps"] in metric, imperial: per 6.28318530717959 canonical,
         /// Represents the degree per second unit of angular velocity.
-        RotationsPerMinute: 9.54929658551372 per canonical,
+        RotationsPerMinute ["rpm", "rotation per minute", "rotations per minute", "rot/min"] in metric, imperial: 9.54929658551372 per canonical,
         /// Represents the degree per second unit of angular velocity.
-        DegreesPerSecond: 57.2957795130823 per canonical,
+        DegreesPerSecond ["°/s", "degree per second", "degrees per second", "deg/s"] in metric, imperial: 57.2957795130823 per canonical,
     } where {
-        Self * Time => Angle in Radians,
-        Self / Time => AngularAcceleration in RadiansPerSecondSquared,
+        Self / Time <=> AngularAcceleration in RadiansPerSecondSquared,
     }
 );
 dimension!(
     pub AngularAcceleration {
         canonical: RadiansPerSecondSquared,
+        exponents: [angle: 1, time: -2],
 
         /// Represents the radian per second squared unit of angular acceleration.
-        RadiansPerSecondSquared: 1.0 per canonical,
+        RadiansPerSecondSquared ["rad/s²", "radian per second squared", "radians per second squared", "rad/s^2"] in metric, imperial: 1.0 per canonical,
         /// Represents the rotation per second squared unit of angular acceleration.
-        RotationsPerSecondSquared: per 6.28318530717959 canonical,
+        RotationsPerSecondSquared ["rot/s²", "rotation per second squared", "rotations per second squared", "rot/s^2"] in metric, imperial: per 6.28318530717959 canonical,
         /// Represents the rotations per minute squared unit of angular acceleration.
-        RotationsPerMinuteSquared: 572.957795130823 per canonical,
+        RotationsPerMinuteSquared ["rot/min²", "rotation per minute squared", "rotations per minute squared", "rot/min^2"] in metric, imperial: 572.957795130823 per canonical,
         /// Represents the degree per second squared unit of angular acceleration.
-        DegreesPerSecondSquared: 57.2957795130823 per canonical,
-    } where {
-        Self * Time => AngularVelocity in RadiansPerSecond,
+        DegreesPerSecondSquared ["°/s²", "degree per second squared", "degrees per second squared", "deg/s^2"] in metric, imperial: 57.2957795130823 per canonical,
     }
 );
 
@@ -254,31 +334,32 @@ dimension!(
     /// Canonically represented in kilograms.
     pub Mass {
         canonical: Kilograms,
+        exponents: [mass: 1],
 
         /// Represents the microgram unit of mass.
-        Micrograms: 1_000_000_000.0 per canonical,
+        Micrograms ["µg", "microgram", "micrograms", "ug"] in metric: 1_000_000_000.0 per canonical,
         /// Represents the milligram unit of mass.
-        Milligrams: 1_000_000.0 per canonical,
+        Milligrams ["mg", "milligram", "milligrams"] in metric: 1_000_000.0 per canonical,
         /// Represents the gram unit of mass.
-        Grams: 1_000.0 per canonical,
+        Grams ["g", "gram", "grams"] in metric: 1_000.0 per canonical,
         /// Represents the kilogram unit of mass.
-        Kilograms: 1.0 per canonical,
+        Kilograms ["kg", "kilogram", "kilograms"] in metric: 1.0 per canonical,
 
         /// Represents the ton unit of mass.
-        Pounds: per 0.45359237 canonical,
+        Pounds ["lb", "pound", "pounds", "lbs"] in imperial: per 0.45359237 canonical,
         /// Represents the ounces unit of mass.
-        Ounces: 35.2739619495804 per canonical,
+        Ounces ["oz", "ounce", "ounces"] in imperial: per 0.028349523125 canonical,
         /// Represents the stone unit of mass.
-        Stones: per 6.35029318 canonical,
+        Stones ["st", "stone", "stones"]: per 6.35029318 canonical,
 
         /// Represents the tonne unit of mass. Defined as one megagram.
-        MetricTons: per 1000.0 canonical,
+        MetricTons ["t", "metric ton", "metric tons", "tonne", "tonnes"] in metric: per 1000.0 canonical,
         /// Represents the American (short) ton unit of mass. Defined as 2000 pounds.
-        ShortTons: per 907.18474 canonical,
+        ShortTons ["tn", "short ton", "short tons"] in imperial: per 907.18474 canonical,
         /// Represents the British (long) ton unit of mass. Defined as 2240 pounds.
-        LongTons: per 1016.0469088 canonical,
+        LongTons ["LT", "long ton", "long tons"]: per 1016.0469088 canonical,
     } where {
-        Self * LinearAcceleration => Force in Newtons,
+        Self * LinearAcceleration <=> Force in Newtons,
     }
 );
 
@@ -286,21 +367,27 @@ dimension!(
     /// Represents force.
     ///
     /// Canonically represented in newtons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// # fn close(a: f64, b: f64) -> bool { (a - b).abs() < 1e-9 }
+    /// assert!(close((1.0f64 * PoundsForce).to::<Newtons>(), 4.4482216152605));
+    /// assert!(close((100.0f64 * Newtons).to::<PoundsForce>(), 22.48089430997105));
+    /// ```
     pub Force {
         canonical: Newtons,
+        exponents: [mass: 1, length: 1, time: -2],
 
         /// Represents the newton unit of force.
         /// This is the standard SI unit of force.
-        Newtons: 1.0 per canonical,
+        Newtons ["N", "newton", "newtons"] in metric: 1.0 per canonical,
         /// Represents the pound-force unit of force.
-        PoundsForce: 4.4482216 per canonical,
+        PoundsForce ["lbf", "pound-force", "pounds-force"] in imperial: per 4.4482216152605 canonical,
         /// Represents the dyne unit of force.
-        Dynes: per 1e-05 canonical,
-    } where {
-        Self * Length => Energy in Joules,
-        Self / LinearAcceleration => Mass in Kilograms,
-        Self / Mass => LinearAcceleration in MetersPerSecondSquared,
-        Self / Area => Pressure in Pascals,
+        Dynes ["dyn", "dyne", "dynes"]: per 1e-05 canonical,
     }
 );
 
@@ -310,18 +397,19 @@ dimension!(
     /// Canonically represented in pascals.
     pub Pressure {
         canonical: Pascals,
+        exponents: [mass: 1, length: -1, time: -2],
 
         /// Represents the pascal unit of pressure.
         /// This is the standard SI unit of pressure.
-        Pascals: 1.0 per canonical,
+        Pascals ["Pa", "pascal", "pascals"] in metric: 1.0 per canonical,
         /// Represents the PSI (Pounds-force per Square Inch) unit of pressure.
-        Psi: per 6894.75729316836 canonical,
+        Psi ["psi", "pound per square inch", "pounds per square inch"] in imperial: per 6894.75729316836 canonical,
         /// Represents the atmosphere unit of pressure.
-        Atmospheres: per 101325.0 canonical,
+        Atmospheres ["atm", "atmosphere", "atmospheres"]: per 101325.0 canonical,
         /// Represents the bar unit of pressure.
-        Bars: per 100_000.0 canonical,
+        Bars ["bar", "bar", "bars"] in metric: per 100_000.0 canonical,
     } where {
-        Self * Area => Force in Newtons,
+        Self * Area <=> Force in Newtons,
     }
 );
 
@@ -335,21 +423,22 @@ dimension!(
     /// However, Shrewnit makes angle a base dimension which means that torque is measured in N*m/rad.
     pub Torque {
         canonical: NewtonMetersPerRadian,
+        exponents: [mass: 1, length: 2, time: -2, angle: -1],
 
         /// Represents the newton meter per radian unit of torque.
-        NewtonMetersPerRadian: 1.0 per canonical,
+        NewtonMetersPerRadian ["N·m/rad", "newton meter per radian", "newton meters per radian", "Nm/rad"] in metric: 1.0 per canonical,
         /// Represents the newton meter per degree unit of torque.
-        NewtonMetersPerDegree: per 57.2957795130823 canonical,
+        NewtonMetersPerDegree ["N·m/°", "newton meter per degree", "newton meters per degree", "Nm/deg"] in metric: per 57.2957795130823 canonical,
 
         /// Represents the pound-foot per radian unit of torque.
-        PoundFeetPerRadian: per 1.3558179483314 canonical,
+        PoundFeetPerRadian ["lb·ft/rad", "pound foot per radian", "pound feet per radian", "lbft/rad"] in imperial: per 1.3558179483314 canonical,
         /// Represents the pound-foot per degree unit of torque.
-        PoundFeetPerDegree: per 77.6826462274756 canonical,
+        PoundFeetPerDegree ["lb·ft/°", "pound foot per degree", "pound feet per degree", "lbft/deg"] in imperial: per 77.6826462274756 canonical,
 
         /// Represents the dyne centimeter per radian unit of torque.
-        DyneCentimetersPerRadians: 10_000_000.0 per canonical,
+        DyneCentimetersPerRadians ["dyn·cm/rad", "dyne centimeter per radian", "dyne centimeters per radian"]: 10_000_000.0 per canonical,
     } where {
-        Self * Angle => Energy in Joules,
+        Self * Angle <=> Energy in Joules,
     }
 );
 
@@ -357,23 +446,51 @@ dimension!(
     /// Represents energy.
     ///
     /// Canonically represented in joules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// # fn close(a: f64, b: f64) -> bool { (a - b).abs() < 1e-9 }
+    /// assert!(close((1.0f64 * Joules).to::<Ergs>(), 1e7));
+    /// assert!(close((1.0f64 * Calories).to::<Ergs>(), 4.184e7));
+    /// ```
     pub Energy {
         canonical: Joules,
+        exponents: [mass: 1, length: 2, time: -2],
 
         /// Represents the joule unit of energy.
-        Joules: 1.0 per canonical,
+        Joules ["J", "joule", "joules"] in metric: 1.0 per canonical,
         /// Represents the calorie unit of energy.
-        Calories: per 4.184 canonical,
+        Calories ["cal", "calorie", "calories"]: per 4.184 canonical,
         /// Represents the kilocalorie unit of energy.
-        Kilocalories: per 4184.0 canonical,
+        Kilocalories ["kcal", "kilocalorie", "kilocalories", "Cal"]: per 4184.0 canonical,
         /// Represents the erg unit of energy.
-        Ergs: 10e-7 per canonical,
+        Ergs ["erg", "erg", "ergs"]: per 1e-7 canonical,
         /// Represents the watt-hour unit of energy.
-        WattHours: per 3600.0 canonical,
+        WattHours ["Wh", "watt hour", "watt hours", "watt-hour", "watt-hours"] in metric: per 3600.0 canonical,
+    }
+);
+
+dimension!(
+    /// Represents specific energy, the energy per unit of mass.
+    ///
+    /// This is also the dimension of a linear velocity squared.
+    ///
+    /// Canonically represented in joules per kilogram.
+    pub SpecificEnergy {
+        canonical: JoulesPerKilogram,
+        exponents: [length: 2, time: -2],
+
+        /// Represents the joule per kilogram unit of specific energy.
+        JoulesPerKilogram ["J/kg", "joule per kilogram", "joules per kilogram"] in metric: 1.0 per canonical,
+        /// Represents the kilojoule per kilogram unit of specific energy.
+        KilojoulesPerKilogram ["kJ/kg", "kilojoule per kilogram", "kilojoules per kilogram"] in metric: per 1000.0 canonical,
+        /// Represents the BTU per pound unit of specific energy.
+        BtusPerPound ["BTU/lb", "BTU per pound", "BTUs per pound"] in imperial: per 2326.0 canonical,
     } where {
-        Self / Length => Force in Newtons,
-        Self / Angle => Torque in NewtonMetersPerRadian,
-        Self / Time => Power in Watts,
+        Self * Mass <=> Energy in Joules,
     }
 );
 
@@ -381,24 +498,32 @@ dimension!(
     /// Represents power.
     ///
     /// Canonically represented in watts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// # fn close(a: f64, b: f64) -> bool { (a - b).abs() < 1e-9 }
+    /// assert!(close((1e7f64 * ErgsPerSecond).to::<Watts>(), 1.0));
+    /// ```
     pub Power {
         canonical: Watts,
+        exponents: [mass: 1, length: 2, time: -3],
 
         /// Represents the watt unit of power.
         /// This is the standard SI unit of power.
-        Watts: 1.0 per canonical,
+        Watts ["W", "watt", "watts"] in metric: 1.0 per canonical,
         /// Represents the horsepower unit of power.
-        Horsepower: per 745.69987158227 canonical,
+        Horsepower ["hp", "horsepower", "horsepower"] in imperial: per 745.69987158227 canonical,
 
         /// Represents the ergs per second unit of power.
-        ErgsPerSecond: 10e-7 per canonical,
+        ErgsPerSecond ["erg/s", "erg per second", "ergs per second"]: per 1e-7 canonical,
 
         /// Represents the foot-pounds per minute unit of power.
-        FootPoundsPerMinute: 44.2537289566359 per canonical,
+        FootPoundsPerMinute ["ft·lbf/min", "foot-pound per minute", "foot-pounds per minute", "ft-lbf/min"]: 44.2537289566359 per canonical,
     } where {
-        Self / Voltage => Current in Amperes,
-        Self / Current => Voltage in Volts,
-        Self * Time => Energy in Joules,
+        Self * Time <=> Energy in Joules,
     }
 );
 
@@ -408,71 +533,138 @@ dimension!(
     /// Canonically represented in volts.
     pub Voltage {
         canonical: Volts,
+        exponents: [mass: 1, length: 2, time: -3, current: -1],
 
         /// Represents the millivolt unit of voltage.
-        Millivolts: 1000.0 per canonical,
+        Millivolts ["mV", "millivolt", "millivolts"] in metric, imperial: 1000.0 per canonical,
         /// Represents the volt unit of voltage.
         /// This is the standard SI unit of voltage.
-        Volts: 1.0 per canonical,
+        Volts ["V", "volt", "volts"] in metric, imperial: 1.0 per canonical,
         /// Represents the kilovolt unit of voltage.
-        Kilovolts: per 1000.0 canonical,
+        Kilovolts ["kV", "kilovolt", "kilovolts"] in metric, imperial: per 1000.0 canonical,
     } where {
-        Self * Current => Power in Watts,
+        Self * Current <=> Power in Watts,
     }
 );
 
 dimension!(
     pub Current {
         canonical: Amperes,
+        exponents: [current: 1],
 
         /// Represents the milliampere unit of current.
-        Milliamperes: 1000.0 per canonical,
+        Milliamperes ["mA", "milliampere", "milliamperes", "milliamp", "milliamps"] in metric, imperial: 1000.0 per canonical,
         /// Represents the ampere unit of current.
         /// This is the standard SI unit of current.
-        Amperes: 1.0 per canonical,
+        Amperes ["A", "ampere", "amperes", "amp", "amps"] in metric, imperial: 1.0 per canonical,
         /// Represents the kiloampere unit of current.
-        Kiloamperes: per 1000.0 canonical,
-    } where {
-        Self * Voltage => Power in Watts,
+        Kiloamperes ["kA", "kiloampere", "kiloamperes"] in metric, imperial: per 1000.0 canonical,
     }
 );
 
 dimension!(
-    /// Represents temperature.
-    pub Temperature {
-        canonical: Kelvin,
-
-        /// Represents the kelvin unit of temperature.
-        Kelvin: 1.0 per canonical,
+    /// Represents a dimensionless ratio, such as an efficiency, a gain or a slip factor.
+    ///
+    /// This is the result of dividing any quantity by another quantity of the same dimension,
+    /// and multiplying a quantity by a ratio keeps its dimension.
+    ///
+    /// Canonically represented as a plain number.
+    pub Ratio {
+        canonical: Unity,
+        exponents: [],
+
+        /// Represents a ratio as a plain number.
+        Unity ["", "", ""]: 1.0 per canonical,
+        /// Represents the percent unit of ratio.
+        Percent ["%", "percent", "percent"]: 100.0 per canonical,
+        /// Represents the per mille unit of ratio.
+        PerMille ["‰", "per mille", "per mille"]: 1000.0 per canonical,
+        /// Represents the parts per million unit of ratio.
+        PartsPerMillion ["ppm", "part per million", "parts per million"]: 1_000_000.0 per canonical,
     }
 );
 
-unit_type!(
-    /// Represents the celsius unit of temperature.
-    pub Celsius of dimension Temperature
-);
-impl<S: Scalar> UnitOf<S, Temperature<S>> for Celsius {
-    fn from_canonical(canonical: S) -> S {
-        canonical + S::from_f64(273.15).unwrap()
-    }
+dimension!(
+    /// Represents an absolute temperature.
+    ///
+    /// Absolute temperatures can't be added together, because the result would depend on the zero point of the scale.
+    /// Subtracting two temperatures gives a [`TemperatureInterval`],
+    /// which can be added to or subtracted from a temperature.
+    ///
+    /// Canonically represented in kelvin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// # fn close(a: f64, b: f64) -> bool { (a - b).abs() < 1e-9 }
+    /// assert!(close((0.0f64 * Celsius).to::<Kelvin>(), 273.15));
+    /// assert!(close((100.0f64 * Celsius).to::<Kelvin>(), 373.15));
+    /// assert!(close((32.0f64 * Fahrenheit).to::<Kelvin>(), 273.15));
+    /// assert!(close((212.0f64 * Fahrenheit).to::<Kelvin>(), 373.15));
+    /// assert!(close((0.0f64 * Rankine).to::<Kelvin>(), 0.0));
+    /// assert!(close((491.67f64 * Rankine).to::<Kelvin>(), 273.15));
+    /// assert!(close((80.0f64 * Reaumur).to::<Kelvin>(), 373.15));
+    ///
+    /// // Celsius, Fahrenheit and Réaumur aren't `One` units, so use the `Dimension` trait to convert into them.
+    /// assert!(close(Dimension::to::<Fahrenheit>(&(-40.0f64 * Celsius)), -40.0));
+    /// assert!(close(Dimension::to::<Reaumur>(&(212.0f64 * Fahrenheit)), 80.0));
+    ///
+    /// let rise: TemperatureInterval = 25.0f64 * Celsius - 20.0f64 * Celsius;
+    /// assert!(close(rise.to::<DeltaFahrenheit>(), 9.0));
+    /// assert!(close(Dimension::to::<Fahrenheit>(&(68.0f64 * Fahrenheit + rise)), 77.0));
+    /// ```
+    ///
+    /// Adding two absolute temperatures doesn't compile.
+    ///
+    /// ```compile_fail
+    /// use shrewnit::prelude::*;
+    ///
+    /// let nonsense = 20.0f64 * Celsius + 30.0f64 * Celsius;
+    /// ```
+    ///
+    /// Absolute temperatures have no exponents, so they can't be added through a [`Quantity`](crate::quantity::Quantity) either.
+    ///
+    /// ```compile_fail
+    /// use shrewnit::{prelude::*, quantity::Quantity};
+    ///
+    /// let nonsense = Quantity::from(20.0f64 * Celsius) + Quantity::from(30.0f64 * Celsius);
+    /// ```
+    pub Temperature with differences in TemperatureInterval {
+        canonical: Kelvin,
 
-    fn to_canonical(converted: S) -> S {
-        converted - S::from_f64(273.15).unwrap()
+        /// Represents the kelvin unit of temperature.
+        /// This is the standard SI unit of temperature.
+        Kelvin ["K", "kelvin", "kelvins"] in metric: 1.0 per canonical,
+        /// Represents the celsius unit of temperature.
+        Celsius ["°C", "degree Celsius", "degrees Celsius", "degC", "celsius"] in metric: 1.0 per canonical offset 273.15,
+        /// Represents the fahrenheit unit of temperature.
+        Fahrenheit ["°F", "degree Fahrenheit", "degrees Fahrenheit", "degF", "fahrenheit"] in imperial: 1.8 per canonical zero at -459.67,
+        /// Represents the rankine unit of temperature.
+        Rankine ["°R", "degree Rankine", "degrees Rankine", "degR", "rankine"] in imperial: 1.8 per canonical,
+        /// Represents the réaumur unit of temperature.
+        Reaumur ["°Ré", "degree Réaumur", "degrees Réaumur", "°Re", "reaumur"]: per 1.25 canonical offset 273.15,
     }
-}
-
-unit_type!(
-    /// Represents the celsius unit of temperature.
-    pub Fahrenheit of dimension Temperature
 );
-impl<S: Scalar> UnitOf<S, Temperature<S>> for Fahrenheit {
-    fn from_canonical(canonical: S) -> S {
-        (canonical + S::from_f64(459.67).unwrap()) * S::from_f64(5.0).unwrap()
-            / S::from_f64(9.0).unwrap()
-    }
 
-    fn to_canonical(converted: S) -> S {
-        (converted * S::from_f64(9.0).unwrap() / S::from_f64(5.0).unwrap())
-            - S::from_f64(273.15).unwrap()
+dimension!(
+    /// Represents a difference between two temperatures.
+    ///
+    /// Canonically represented in kelvin.
+    pub TemperatureInterval {
+        canonical: DeltaKelvin,
+        exponents: [temperature: 1],
+
+        /// Represents a temperature difference of one kelvin.
+        DeltaKelvin ["K", "kelvin", "kelvins"] in metric: 1.0 per canonical,
+        /// Represents a temperature difference of one degree celsius.
+        DeltaCelsius ["°C", "degree Celsius", "degrees Celsius", "degC", "celsius"] in metric: 1.0 per canonical,
+        /// Represents a temperature difference of one degree fahrenheit.
+        DeltaFahrenheit ["°F", "degree Fahrenheit", "degrees Fahrenheit", "degF", "fahrenheit"] in imperial: 1.8 per canonical,
+        /// Represents a temperature difference of one degree rankine.
+        DeltaRankine ["°R", "degree Rankine", "degrees Rankine", "degR", "rankine"] in imperial: 1.8 per canonical,
+        /// Represents a temperature difference of one degree réaumur.
+        DeltaReaumur ["°Ré", "degree Réaumur", "degrees Réaumur", "°Re", "reaumur"]: 0.8 per canonical,
     }
-}
+);
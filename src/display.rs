@@ -0,0 +1,254 @@
+//! Formatting quantities as text.
+//!
+//! Every dimension created with [`dimension!`](crate::dimension) implements [`Display`](core::fmt::Display),
+//! printing its value in the canonical unit followed by the unit's symbol.
+//! [`Dimension::display_in`](crate::Dimension::display_in) prints a quantity in any unit implementing [`UnitInfo`].
+//!
+//! The width, fill, alignment, sign and precision flags apply to the whole quantity,
+//! and the alternate flag prints the name of the unit instead of its symbol.
+//! Formatting doesn't allocate, so quantities can be written to fixed buffers through [`core::fmt::Write`].
+//!
+//! The `humanize` function of every dimension picks a unit automatically, see [`Humanized`].
+//!
+//! ```
+//! use shrewnit::prelude::*;
+//!
+//! let length = 1.0f64 * Feet;
+//!
+//! assert_eq!(format!("{}", length.display_in::<Feet>()), "1 ft");
+//! assert_eq!(format!("{:.2}", length), "0.30 m");
+//! assert_eq!(format!("{:#}", length.display_in::<Feet>()), "1 foot");
+//! assert_eq!(format!("{:#.1}", length.display_in::<Inches>()), "12.0 inches");
+//! assert_eq!(format!("[{:>8}]", length.display_in::<Feet>()), "[    1 ft]");
+//! ```
+
+use core::{
+    fmt::{self, Write},
+    marker::PhantomData,
+};
+
+use crate::{
+    units::{UnitDescriptor, UnitInfo},
+    Scalar,
+};
+
+/// Displays a quantity in the unit `U`.
+///
+/// Created by [`Dimension::display_in`](crate::Dimension::display_in).
+#[derive(Clone, Copy)]
+pub struct DisplayIn<S: Scalar, U> {
+    value: S,
+    unit: PhantomData<U>,
+}
+
+impl<S: Scalar, U> DisplayIn<S, U> {
+    /// Displays a value that is already in the unit `U`.
+    #[inline]
+    pub fn new(value: S) -> Self {
+        Self {
+            value,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<S: Scalar + fmt::Display, U: UnitInfo> fmt::Display for DisplayIn<S, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_quantity(f, &self.value, U::SYMBOL, U::NAME, U::PLURAL)
+    }
+}
+
+/// Displays a quantity in the unit that gives it the most readable magnitude.
+///
+/// Created by the `humanize` and `humanize_in` functions of every dimension.
+/// The unit is chosen from the linear units of the dimension,
+/// as the largest unit in which the magnitude of the quantity is at least one.
+///
+/// ```
+/// use shrewnit::{prelude::*, units::UnitSystem};
+///
+/// assert_eq!((1200.0f64 * Meters).humanize().to_string(), "1.2 km");
+/// assert_eq!(format!("{:.0}", (0.00035f64 * Seconds).humanize()), "350 µs");
+/// assert_eq!(format!("{:.1}", (90.0f64 * Minutes).humanize()), "1.5 h");
+///
+/// assert_eq!(format!("{:.2}", (5.0f64 * Kilometers).humanize_in(UnitSystem::Imperial)), "3.11 mi");
+/// assert_eq!(format!("{:#.1}", (30.0f64 * Inches).humanize_in(UnitSystem::Imperial)), "2.5 feet");
+///
+/// let humanized = (2500.0f64 * Grams).humanize();
+/// assert_eq!(humanized.value(), 2.5);
+/// assert_eq!(humanized.unit().symbol, "kg");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Humanized<S: Scalar> {
+    value: S,
+    unit: UnitDescriptor,
+}
+
+impl<S: Scalar> Humanized<S> {
+    /// Displays a value that is already in the given unit.
+    #[inline]
+    pub fn new(value: S, unit: UnitDescriptor) -> Self {
+        Self { value, unit }
+    }
+
+    /// Returns the value in the chosen unit.
+    #[inline]
+    pub fn value(&self) -> S {
+        self.value.clone()
+    }
+
+    /// Returns the chosen unit.
+    #[inline]
+    pub fn unit(&self) -> UnitDescriptor {
+        self.unit
+    }
+}
+
+impl<S: Scalar + fmt::Display> fmt::Display for Humanized<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_quantity(
+            f,
+            &self.value,
+            self.unit.symbol,
+            self.unit.name,
+            self.unit.plural,
+        )
+    }
+}
+
+/// Writes a value followed by a unit, applying the flags of the formatter to the whole quantity.
+fn write_quantity<S: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    value: &S,
+    symbol: &str,
+    name: &str,
+    plural: &str,
+) -> fmt::Result {
+    // Measure the number first, so the padding can be written without allocating.
+    let (sign_plus, precision) = (f.sign_plus(), f.precision());
+    let mut measure = Measure::default();
+    write_number(&mut measure, value, sign_plus, precision)?;
+
+    let unit = match (f.alternate(), measure.is_one()) {
+        (false, _) => symbol,
+        (true, true) => name,
+        (true, false) => plural,
+    };
+    let len = if unit.is_empty() {
+        measure.len
+    } else {
+        measure.len + 1 + unit.chars().count()
+    };
+
+    write_padded(f, len, |f| {
+        write_number(f, value, sign_plus, precision)?;
+        if !unit.is_empty() {
+            f.write_char(' ')?;
+            f.write_str(unit)?;
+        }
+        Ok(())
+    })
+}
+
+/// Writes text of the given length, padded according to the width, fill and alignment flags.
+pub(crate) fn write_padded<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(
+    f: &mut fmt::Formatter<'_>,
+    len: usize,
+    write: F,
+) -> fmt::Result {
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+
+    for _ in 0..before {
+        f.write_char(f.fill())?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        f.write_char(f.fill())?;
+    }
+    Ok(())
+}
+
+/// Writes text made of formatted numbers, like a scalar holding several numbers,
+/// padded according to the width, fill and alignment flags.
+///
+/// `write` is given the sign and precision flags of the formatter, to apply to the numbers with [`write_number`].
+/// It's called once to measure the text, so the padding can be written without allocating, and once to write it.
+pub(crate) fn write_padded_numbers<F>(f: &mut fmt::Formatter<'_>, write: F) -> fmt::Result
+where
+    F: Fn(&mut dyn Write, bool, Option<usize>) -> fmt::Result,
+{
+    let (sign_plus, precision) = (f.sign_plus(), f.precision());
+    let mut measure = Measure::default();
+    write(&mut measure, sign_plus, precision)?;
+
+    write_padded(f, measure.len, |f| write(f, sign_plus, precision))
+}
+
+/// Writes a number with the given sign and precision flags.
+pub(crate) fn write_number<W: Write + ?Sized, S: fmt::Display>(
+    w: &mut W,
+    value: &S,
+    sign_plus: bool,
+    precision: Option<usize>,
+) -> fmt::Result {
+    match (sign_plus, precision) {
+        (false, None) => write!(w, "{}", value),
+        (false, Some(precision)) => write!(w, "{:.*}", precision, value),
+        (true, None) => write!(w, "{:+}", value),
+        (true, Some(precision)) => write!(w, "{:+.*}", precision, value),
+    }
+}
+
+/// Counts the characters of a formatted number, and checks if it is exactly one.
+pub(crate) struct Measure {
+    pub(crate) len: usize,
+    one: OneState,
+}
+
+impl Default for Measure {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            one: OneState::Start,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum OneState {
+    Start,
+    Sign,
+    One,
+    Fraction,
+    Other,
+}
+
+impl Measure {
+    pub(crate) fn is_one(&self) -> bool {
+        match self.one {
+            OneState::One | OneState::Fraction => true,
+            _ => false,
+        }
+    }
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.len += 1;
+            self.one = match (self.one, c) {
+                (OneState::Start, '+') | (OneState::Start, '-') => OneState::Sign,
+                (OneState::Start, '1') | (OneState::Sign, '1') => OneState::One,
+                (OneState::One, '.') => OneState::Fraction,
+                (OneState::Fraction, '0') => OneState::Fraction,
+                _ => OneState::Other,
+            };
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+//! Conversions between [`Time`] and [`core::time::Duration`].
+//!
+//! A `Duration` converts into a `Time<f64>` or `Time<f32>` with `From`,
+//! and a `Time` converts back with `TryFrom`, which fails for times that a `Duration` can't represent.
+//! Durations can also be added to and subtracted from `f64` and `f32` times directly.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use core::{convert::TryFrom, time::Duration};
+//! use shrewnit::duration::DurationError;
+//!
+//! let time = Time::<f64>::from(Duration::from_millis(1500));
+//! assert_eq!(time.to::<Seconds>(), 1.5);
+//!
+//! assert_eq!(Duration::try_from(2.0f64 * Minutes), Ok(Duration::from_secs(120)));
+//! assert_eq!(Duration::try_from(-1.0f64 * Seconds), Err(DurationError::Negative));
+//!
+//! let timeout = 1.0f64 * Seconds + Duration::from_millis(250);
+//! assert_eq!(timeout.to::<Milliseconds>(), 1250.0);
+//! ```
+//!
+//! Other scalars convert durations with [`Time::try_from_duration`] and [`Time::checked_add_duration`],
+//! which fail instead of truncating a fraction of a second that the scalar can't hold.
+//! Integer times also convert with `TryFrom<Duration>`.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use core::{convert::TryFrom, time::Duration};
+//! use shrewnit::units::ConversionError;
+//!
+//! assert_eq!(Time::<i32>::try_from(Duration::from_secs(90)), Ok(90 * Seconds));
+//! assert_eq!(
+//!     Time::<i32>::try_from(Duration::from_millis(1500)),
+//!     Err(ConversionError::NotRepresentable)
+//! );
+//!
+//! assert_eq!((1u8 * Seconds).checked_add_duration(Duration::from_secs(2)), Ok(3u8 * Seconds));
+//! assert_eq!(
+//!     (1u8 * Seconds).checked_add_duration(Duration::from_secs(1000)),
+//!     Err(ConversionError::Overflow)
+//! );
+//! ```
+
+use core::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+
+use num_traits::{CheckedAdd, CheckedSub};
+
+use crate::{
+    units::{is_fractional, ConversionError},
+    Dimension, Scalar, Time,
+};
+
+/// The error returned when a [`Time`] can't be converted into a [`Duration`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DurationError {
+    /// The time is negative.
+    Negative,
+    /// The time is NaN.
+    NaN,
+    /// The time is too long to fit in a `Duration`.
+    Overflow,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationError::Negative => f.write_str("time is negative"),
+            DurationError::NaN => f.write_str("time is NaN"),
+            DurationError::Overflow => f.write_str("time is too long to fit in a duration"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationError {}
+
+/// Returns the length of a duration in seconds.
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1e9
+}
+
+impl From<Duration> for Time<f64> {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Dimension::from_canonical(seconds(duration))
+    }
+}
+impl From<Duration> for Time<f32> {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Dimension::from_canonical(seconds(duration) as f32)
+    }
+}
+
+impl<S: Scalar> TryFrom<Time<S>> for Duration {
+    type Error = DurationError;
+    fn try_from(time: Time<S>) -> Result<Self, DurationError> {
+        let seconds = Dimension::canonical(&time)
+            .to_f64()
+            .unwrap_or(core::f64::NAN);
+        if seconds.is_nan() {
+            return Err(DurationError::NaN);
+        }
+        if seconds < 0.0 {
+            return Err(DurationError::Negative);
+        }
+        // 2^64, the first whole number of seconds that doesn't fit in a `u64`.
+        if seconds >= 18_446_744_073_709_551_616.0 {
+            return Err(DurationError::Overflow);
+        }
+
+        let whole = seconds as u64;
+        let nanos = ((seconds - whole as f64) * 1e9 + 0.5) as u32;
+        if nanos >= 1_000_000_000 {
+            let whole = whole.checked_add(1).ok_or(DurationError::Overflow)?;
+            Ok(Duration::new(whole, nanos - 1_000_000_000))
+        } else {
+            Ok(Duration::new(whole, nanos))
+        }
+    }
+}
+
+impl<S: Scalar> Time<S> {
+    /// Converts a duration into a time.
+    ///
+    /// Fails with [`ConversionError::NotRepresentable`] if the duration has a fraction of a second
+    /// and the scalar can't hold fractions, rather than truncating it.
+    pub fn try_from_duration(duration: Duration) -> Result<Self, ConversionError> {
+        let seconds = if duration.subsec_nanos() == 0 {
+            S::from_u64(duration.as_secs())
+        } else if is_fractional::<S>() {
+            S::from_f64(seconds(duration))
+        } else {
+            return Err(ConversionError::NotRepresentable);
+        };
+        seconds
+            .map(Dimension::from_canonical)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Adds a duration to the time, failing if the duration can't be converted or the sum overflows.
+    pub fn checked_add_duration(self, rhs: Duration) -> Result<Self, ConversionError>
+    where
+        S: CheckedAdd,
+    {
+        self.checked_add(Self::try_from_duration(rhs)?)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Subtracts a duration from the time, failing if the duration can't be converted or the difference overflows.
+    pub fn checked_sub_duration(self, rhs: Duration) -> Result<Self, ConversionError>
+    where
+        S: CheckedSub,
+    {
+        self.checked_sub(Self::try_from_duration(rhs)?)
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+macro_rules! try_from_duration_imp {
+    ($($scalar:ty),*) => {
+        $(
+            impl TryFrom<Duration> for Time<$scalar> {
+                type Error = ConversionError;
+                #[inline]
+                fn try_from(duration: Duration) -> Result<Self, ConversionError> {
+                    Time::try_from_duration(duration)
+                }
+            }
+        )*
+    };
+}
+
+try_from_duration_imp!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! duration_ops_imp {
+    ($($scalar:ty),*) => {
+        $(
+            impl Add<Duration> for Time<$scalar> {
+                type Output = Time<$scalar>;
+                #[inline]
+                fn add(self, rhs: Duration) -> Time<$scalar> {
+                    self + Time::<$scalar>::from(rhs)
+                }
+            }
+            impl AddAssign<Duration> for Time<$scalar> {
+                #[inline]
+                fn add_assign(&mut self, rhs: Duration) {
+                    *self = *self + rhs;
+                }
+            }
+            impl Sub<Duration> for Time<$scalar> {
+                type Output = Time<$scalar>;
+                #[inline]
+                fn sub(self, rhs: Duration) -> Time<$scalar> {
+                    self - Time::<$scalar>::from(rhs)
+                }
+            }
+            impl SubAssign<Duration> for Time<$scalar> {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Duration) {
+                    *self = *self - rhs;
+                }
+            }
+        )*
+    };
+}
+
+duration_ops_imp!(f32, f64);
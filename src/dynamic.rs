@@ -0,0 +1,272 @@
+//! Quantities whose dimension is only known at runtime.
+//!
+//! [`DynQuantity`] stores its [exponents](crate::exponents) as a plain array instead of in its type,
+//! which makes it useful for values read from config files, telemetry or user input.
+//! Adding and subtracting checks the dimensions of both sides at runtime,
+//! and a `DynQuantity` can be converted back into any dimension type with known exponents using `TryFrom`.
+//! Multiplying and dividing saturates exponents that overflow, unless done with `checked_mul` and `checked_div`,
+//! so values from untrusted input should use the checked functions.
+//!
+//! Absolute temperatures have no exponents, because they would convert to the same `DynQuantity` as a
+//! [`TemperatureInterval`](crate::dimensions::TemperatureInterval). Subtract a reference temperature to get an interval first.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use core::convert::TryFrom;
+//! use shrewnit::dynamic::DynQuantity;
+//!
+//! let distance = DynQuantity::from(100.0f64 * Meters);
+//! let time = DynQuantity::from(20.0f64 * Seconds);
+//!
+//! let velocity: LinearVelocity = LinearVelocity::try_from(distance / time).unwrap();
+//! assert_eq!(velocity.to::<MetersPerSecond>(), 5.0);
+//!
+//! assert!(distance.checked_add(time).is_err());
+//! assert!(Length::try_from(time).is_err());
+//!
+//! let warming = DynQuantity::from(30.0f64 * Celsius - 20.0f64 * Celsius);
+//! assert!((TemperatureInterval::try_from(warming).unwrap().to::<DeltaCelsius>() - 10.0).abs() < 1e-9);
+//! ```
+
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    ops::{Div, DivAssign, Mul, MulAssign},
+};
+
+use crate::{
+    exponents::{Exponents, BASE_DIMENSIONS},
+    quantity::Quantity,
+    Dimension, Scalar,
+};
+
+/// A quantity with a dimension described by runtime exponents.
+///
+/// The exponents are, in order, those of length, mass, time, current, temperature and angle.
+/// The value is stored in the coherent SI unit of the dimension,
+/// which is the canonical unit of every dimension provided by Shrewnit.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DynQuantity<S: Scalar> {
+    value: S,
+    exponents: [i8; BASE_DIMENSIONS],
+}
+
+impl<S: Scalar> DynQuantity<S> {
+    /// Creates a new quantity from a value in the coherent SI unit of its dimension.
+    #[cfg(feature = "const_operators")]
+    #[inline]
+    pub const fn new(value: S, exponents: [i8; BASE_DIMENSIONS]) -> Self {
+        Self { value, exponents }
+    }
+    /// Creates a new quantity from a value in the coherent SI unit of its dimension.
+    #[cfg(not(feature = "const_operators"))]
+    #[inline]
+    pub fn new(value: S, exponents: [i8; BASE_DIMENSIONS]) -> Self {
+        Self { value, exponents }
+    }
+
+    /// Returns the value of this quantity in the coherent SI unit of its dimension.
+    #[inline]
+    pub fn value(&self) -> S {
+        self.value.clone()
+    }
+
+    /// Returns the value of this quantity in the coherent SI unit of its dimension.
+    #[inline]
+    pub fn into_value(self) -> S {
+        self.value
+    }
+
+    /// Returns the exponents of the dimension of this quantity.
+    #[cfg(feature = "const_operators")]
+    #[inline]
+    pub const fn exponents(&self) -> [i8; BASE_DIMENSIONS] {
+        self.exponents
+    }
+    /// Returns the exponents of the dimension of this quantity.
+    #[cfg(not(feature = "const_operators"))]
+    #[inline]
+    pub fn exponents(&self) -> [i8; BASE_DIMENSIONS] {
+        self.exponents
+    }
+
+    /// Returns `true` if all exponents of this quantity are zero.
+    #[inline]
+    pub fn is_dimensionless(&self) -> bool {
+        self.exponents == [0; BASE_DIMENSIONS]
+    }
+
+    /// Checks that this quantity has the given exponents.
+    #[inline]
+    pub fn expect_exponents(
+        &self,
+        expected: [i8; BASE_DIMENSIONS],
+    ) -> Result<(), DimensionMismatch> {
+        if self.exponents == expected {
+            Ok(())
+        } else {
+            Err(DimensionMismatch {
+                expected,
+                found: self.exponents,
+            })
+        }
+    }
+
+    /// Adds two quantities together, failing if their dimensions differ.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Result<Self, DimensionMismatch> {
+        rhs.expect_exponents(self.exponents)?;
+        Ok(Self::new(self.value + rhs.value, self.exponents))
+    }
+
+    /// Finds the difference between self and rhs, failing if their dimensions differ.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, DimensionMismatch> {
+        rhs.expect_exponents(self.exponents)?;
+        Ok(Self::new(self.value - rhs.value, self.exponents))
+    }
+
+    /// Multiplies two quantities, returning `None` if an exponent of the result doesn't fit in an `i8`.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    /// use shrewnit::dynamic::DynQuantity;
+    ///
+    /// let area = DynQuantity::from(2.0f64 * Meters).checked_mul(DynQuantity::from(3.0f64 * Meters));
+    /// assert_eq!(area, Some(DynQuantity::from(6.0f64 * SquareMeters)));
+    ///
+    /// let huge = DynQuantity::new(1.0f64, [100, 0, 0, 0, 0, 0]);
+    /// assert_eq!(huge.checked_mul(huge), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for (exponent, rhs) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+            *exponent = exponent.checked_add(*rhs)?;
+        }
+        Some(Self::new(self.value * rhs.value, exponents))
+    }
+
+    /// Divides two quantities, returning `None` if an exponent of the result doesn't fit in an `i8`.
+    ///
+    /// ```
+    /// use shrewnit::dynamic::DynQuantity;
+    ///
+    /// let tiny = DynQuantity::new(1.0f64, [0, 0, -100, 0, 0, 0]);
+    /// let huge = DynQuantity::new(1.0f64, [0, 0, 100, 0, 0, 0]);
+    /// assert_eq!(tiny.checked_div(huge), None);
+    /// ```
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for (exponent, rhs) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+            *exponent = exponent.checked_sub(*rhs)?;
+        }
+        Some(Self::new(self.value / rhs.value, exponents))
+    }
+}
+
+/// The error returned when the dimensions of two quantities don't match.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DimensionMismatch {
+    /// The exponents that were expected.
+    pub expected: [i8; BASE_DIMENSIONS],
+    /// The exponents that were found.
+    pub found: [i8; BASE_DIMENSIONS],
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a quantity with exponents {:?}, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimensionMismatch {}
+
+impl<S: Scalar + PartialOrd> PartialOrd for DynQuantity<S> {
+    /// Quantities of different dimensions are not comparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.exponents == other.exponents {
+            self.value.partial_cmp(&other.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: Scalar, D: Dimension<S> + crate::exponents::Dimensioned> From<D> for DynQuantity<S> {
+    #[inline]
+    fn from(value: D) -> Self {
+        Self::new(value.canonical(), D::Exponents::EXPONENTS)
+    }
+}
+impl<S: Scalar, D: Exponents> From<Quantity<S, D>> for DynQuantity<S> {
+    #[inline]
+    fn from(value: Quantity<S, D>) -> Self {
+        Self::new(value.into_value(), D::EXPONENTS)
+    }
+}
+impl<S: Scalar, D: Exponents> TryFrom<DynQuantity<S>> for Quantity<S, D> {
+    type Error = DimensionMismatch;
+    #[inline]
+    fn try_from(value: DynQuantity<S>) -> Result<Self, DimensionMismatch> {
+        value.expect_exponents(D::EXPONENTS)?;
+        Ok(Quantity::new(value.value))
+    }
+}
+
+impl<S: Scalar> Mul<S> for DynQuantity<S> {
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        Self::new(self.value * rhs, self.exponents)
+    }
+}
+impl<S: Scalar> MulAssign<S> for DynQuantity<S> {
+    fn mul_assign(&mut self, rhs: S) {
+        self.value = self.value.clone() * rhs;
+    }
+}
+impl<S: Scalar> Div<S> for DynQuantity<S> {
+    type Output = Self;
+    fn div(self, rhs: S) -> Self {
+        Self::new(self.value / rhs, self.exponents)
+    }
+}
+impl<S: Scalar> DivAssign<S> for DynQuantity<S> {
+    fn div_assign(&mut self, rhs: S) {
+        self.value = self.value.clone() / rhs;
+    }
+}
+
+impl<S: Scalar> Mul for DynQuantity<S> {
+    type Output = Self;
+    /// Multiplies two quantities, saturating exponents that don't fit in an `i8`.
+    ///
+    /// Saturated exponents don't match any dimension, so use [`DynQuantity::checked_mul`] to detect them.
+    fn mul(self, rhs: Self) -> Self {
+        let mut exponents = self.exponents;
+        for (exponent, rhs) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+            *exponent = exponent.saturating_add(*rhs);
+        }
+        Self::new(self.value * rhs.value, exponents)
+    }
+}
+impl<S: Scalar> Div for DynQuantity<S> {
+    type Output = Self;
+    /// Divides two quantities, saturating exponents that don't fit in an `i8`.
+    ///
+    /// Saturated exponents don't match any dimension, so use [`DynQuantity::checked_div`] to detect them.
+    fn div(self, rhs: Self) -> Self {
+        let mut exponents = self.exponents;
+        for (exponent, rhs) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+            *exponent = exponent.saturating_sub(*rhs);
+        }
+        Self::new(self.value / rhs.value, exponents)
+    }
+}
@@ -0,0 +1,363 @@
+//! Type-level dimension exponents.
+//!
+//! Every dimension can be written as a product of powers of the base dimensions:
+//! length, mass, time, current, temperature and angle (Shrewnit treats angle as a base dimension).
+//! This module encodes those powers at the type level so that the dimension of a product or quotient
+//! can be computed by the compiler instead of being declared by hand.
+//!
+//! Most users will not need to touch this module directly.
+//! Instead, declare the exponents of a dimension with the `exponents` line of the [`dimension!`](crate::dimension) macro
+//! and work with the resulting [`Quantity`](crate::quantity::Quantity) type.
+
+use core::marker::PhantomData;
+
+use crate::{Dimension, Scalar};
+
+/// The number of base dimensions tracked by [`Dim`].
+pub const BASE_DIMENSIONS: usize = 6;
+
+/// The type-level integer zero.
+pub enum Z0 {}
+/// The type-level integer one greater than `I`, where `I` is zero or positive.
+pub struct Inc<I>(PhantomData<I>);
+/// The type-level integer one less than `I`, where `I` is zero or negative.
+pub struct Dec<I>(PhantomData<I>);
+
+/// The type-level integer 1.
+pub type P1 = Inc<Z0>;
+/// The type-level integer 2.
+pub type P2 = Inc<P1>;
+/// The type-level integer 3.
+pub type P3 = Inc<P2>;
+/// The type-level integer 4.
+pub type P4 = Inc<P3>;
+/// The type-level integer -1.
+pub type N1 = Dec<Z0>;
+/// The type-level integer -2.
+pub type N2 = Dec<N1>;
+/// The type-level integer -3.
+pub type N3 = Dec<N2>;
+/// The type-level integer -4.
+pub type N4 = Dec<N3>;
+
+/// Implemented by [`Z0`] and every positive type-level integer.
+pub trait NonNegative: Integer {}
+impl NonNegative for Z0 {}
+impl<I: NonNegative> NonNegative for Inc<I> {}
+
+/// Implemented by [`Z0`] and every negative type-level integer.
+pub trait NonPositive: Integer {}
+impl NonPositive for Z0 {}
+impl<I: NonPositive> NonPositive for Dec<I> {}
+
+/// A type-level integer.
+pub trait Integer {
+    /// The runtime value of this integer.
+    const VALUE: i8;
+}
+impl Integer for Z0 {
+    const VALUE: i8 = 0;
+}
+impl<I: NonNegative> Integer for Inc<I> {
+    const VALUE: i8 = I::VALUE + 1;
+}
+impl<I: NonPositive> Integer for Dec<I> {
+    const VALUE: i8 = I::VALUE - 1;
+}
+
+/// Type-level integer addition.
+pub trait IntAdd<Rhs> {
+    type Output;
+}
+impl<R> IntAdd<R> for Z0 {
+    type Output = R;
+}
+impl<I> IntAdd<Z0> for Inc<I> {
+    type Output = Inc<I>;
+}
+impl<I: IntAdd<J>, J> IntAdd<Inc<J>> for Inc<I> {
+    type Output = Inc<Inc<I::Output>>;
+}
+impl<I: IntAdd<J>, J> IntAdd<Dec<J>> for Inc<I> {
+    type Output = I::Output;
+}
+impl<I> IntAdd<Z0> for Dec<I> {
+    type Output = Dec<I>;
+}
+impl<I: IntAdd<J>, J> IntAdd<Dec<J>> for Dec<I> {
+    type Output = Dec<Dec<I::Output>>;
+}
+impl<I: IntAdd<J>, J> IntAdd<Inc<J>> for Dec<I> {
+    type Output = I::Output;
+}
+
+/// Type-level integer negation.
+pub trait IntNeg {
+    type Output;
+}
+impl IntNeg for Z0 {
+    type Output = Z0;
+}
+impl<I: IntNeg> IntNeg for Inc<I> {
+    type Output = Dec<I::Output>;
+}
+impl<I: IntNeg> IntNeg for Dec<I> {
+    type Output = Inc<I::Output>;
+}
+
+/// Type-level integer subtraction.
+pub trait IntSub<Rhs> {
+    type Output;
+}
+impl<L: IntAdd<R::Output>, R: IntNeg> IntSub<R> for L {
+    type Output = L::Output;
+}
+
+/// Type-level integer multiplication.
+pub trait IntMul<Rhs> {
+    type Output;
+}
+impl<R> IntMul<R> for Z0 {
+    type Output = Z0;
+}
+impl<I: IntMul<R>, R> IntMul<R> for Inc<I>
+where
+    I::Output: IntAdd<R>,
+{
+    type Output = <I::Output as IntAdd<R>>::Output;
+}
+impl<I: IntMul<R>, R> IntMul<R> for Dec<I>
+where
+    I::Output: IntSub<R>,
+{
+    type Output = <I::Output as IntSub<R>>::Output;
+}
+
+/// Type-level division by two. Only implemented for even integers.
+pub trait IntHalf {
+    type Output;
+}
+impl IntHalf for Z0 {
+    type Output = Z0;
+}
+impl<I: IntHalf> IntHalf for Inc<Inc<I>> {
+    type Output = Inc<I::Output>;
+}
+impl<I: IntHalf> IntHalf for Dec<Dec<I>> {
+    type Output = Dec<I::Output>;
+}
+
+/// Type-level division by three. Only implemented for multiples of three.
+pub trait IntThird {
+    type Output;
+}
+impl IntThird for Z0 {
+    type Output = Z0;
+}
+impl<I: IntThird> IntThird for Inc<Inc<Inc<I>>> {
+    type Output = Inc<I::Output>;
+}
+impl<I: IntThird> IntThird for Dec<Dec<Dec<I>>> {
+    type Output = Dec<I::Output>;
+}
+
+/// The exponents of a dimension over the base dimensions.
+///
+/// The type parameters are, in order, the exponents of length, mass, time, current, temperature and angle.
+pub struct Dim<L, M, T, I, Th, A>(PhantomData<(L, M, T, I, Th, A)>);
+
+/// The exponents of a dimensionless quantity.
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// Implemented by every [`Dim`] whose exponents are all type-level integers.
+pub trait Exponents {
+    /// The runtime exponents of length, mass, time, current, temperature and angle.
+    const EXPONENTS: [i8; BASE_DIMENSIONS];
+}
+impl<L, M, T, I, Th, A> Exponents for Dim<L, M, T, I, Th, A>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    A: Integer,
+{
+    const EXPONENTS: [i8; BASE_DIMENSIONS] = [
+        L::VALUE,
+        M::VALUE,
+        T::VALUE,
+        I::VALUE,
+        Th::VALUE,
+        A::VALUE,
+    ];
+}
+
+/// The exponents of the product of two dimensions.
+pub trait DimMul<Rhs> {
+    type Output;
+}
+impl<L1, M1, T1, I1, Th1, A1, L2, M2, T2, I2, Th2, A2> DimMul<Dim<L2, M2, T2, I2, Th2, A2>>
+    for Dim<L1, M1, T1, I1, Th1, A1>
+where
+    L1: IntAdd<L2>,
+    M1: IntAdd<M2>,
+    T1: IntAdd<T2>,
+    I1: IntAdd<I2>,
+    Th1: IntAdd<Th2>,
+    A1: IntAdd<A2>,
+{
+    type Output = Dim<L1::Output, M1::Output, T1::Output, I1::Output, Th1::Output, A1::Output>;
+}
+
+/// The exponents of the quotient of two dimensions.
+pub trait DimDiv<Rhs> {
+    type Output;
+}
+impl<L1, M1, T1, I1, Th1, A1, L2, M2, T2, I2, Th2, A2> DimDiv<Dim<L2, M2, T2, I2, Th2, A2>>
+    for Dim<L1, M1, T1, I1, Th1, A1>
+where
+    L1: IntSub<L2>,
+    M1: IntSub<M2>,
+    T1: IntSub<T2>,
+    I1: IntSub<I2>,
+    Th1: IntSub<Th2>,
+    A1: IntSub<A2>,
+{
+    type Output = Dim<L1::Output, M1::Output, T1::Output, I1::Output, Th1::Output, A1::Output>;
+}
+
+/// The exponents of a dimension raised to the type-level integer power `E`.
+pub trait DimPow<E> {
+    type Output;
+}
+impl<L, M, T, I, Th, A, E> DimPow<E> for Dim<L, M, T, I, Th, A>
+where
+    L: IntMul<E>,
+    M: IntMul<E>,
+    T: IntMul<E>,
+    I: IntMul<E>,
+    Th: IntMul<E>,
+    A: IntMul<E>,
+{
+    type Output = Dim<L::Output, M::Output, T::Output, I::Output, Th::Output, A::Output>;
+}
+
+/// The exponents of the square root of a dimension. Only implemented when every exponent is even.
+pub trait DimSqrt {
+    type Output;
+}
+impl<L, M, T, I, Th, A> DimSqrt for Dim<L, M, T, I, Th, A>
+where
+    L: IntHalf,
+    M: IntHalf,
+    T: IntHalf,
+    I: IntHalf,
+    Th: IntHalf,
+    A: IntHalf,
+{
+    type Output = Dim<L::Output, M::Output, T::Output, I::Output, Th::Output, A::Output>;
+}
+
+/// The exponents of the cube root of a dimension. Only implemented when every exponent is a multiple of three.
+pub trait DimCbrt {
+    type Output;
+}
+impl<L, M, T, I, Th, A> DimCbrt for Dim<L, M, T, I, Th, A>
+where
+    L: IntThird,
+    M: IntThird,
+    T: IntThird,
+    I: IntThird,
+    Th: IntThird,
+    A: IntThird,
+{
+    type Output = Dim<L::Output, M::Output, T::Output, I::Output, Th::Output, A::Output>;
+}
+
+/// The exponents of the product of the dimensions `A` and `B`.
+pub type Prod<A, B> = <A as DimMul<B>>::Output;
+/// The exponents of the quotient of the dimensions `A` and `B`.
+pub type Quot<A, B> = <A as DimDiv<B>>::Output;
+/// The exponents of the square of the dimension `D`.
+pub type Square<D> = Prod<D, D>;
+/// The exponents of the cube of the dimension `D`.
+pub type Cube<D> = Prod<Square<D>, D>;
+/// The exponents of the dimension `D` raised to the type-level integer power `E`.
+pub type Pow<D, E> = <D as DimPow<E>>::Output;
+/// The exponents of the square root of the dimension `D`.
+pub type Sqrt<D> = <D as DimSqrt>::Output;
+/// The exponents of the cube root of the dimension `D`.
+pub type Cbrt<D> = <D as DimCbrt>::Output;
+
+/// Implemented by dimension types whose exponents over the base dimensions are known.
+///
+/// This trait is implemented by the [`dimension!`](crate::dimension) macro when an `exponents` line is given.
+pub trait Dimensioned {
+    /// The exponents of this dimension.
+    type Exponents: Exponents;
+}
+
+/// Maps a set of exponents back to the dimension type that Shrewnit provides for it.
+///
+/// This is implemented for the exponents of every dimension in [`dimensions`](crate::dimensions).
+pub trait NamedDimension<S: Scalar> {
+    /// The dimension type with these exponents.
+    type Dimension: Dimension<S> + Dimensioned<Exponents = Self>;
+}
+
+/// The dimension type Shrewnit provides for the exponents `D`, with the scalar type `S`.
+pub type Named<D, S> = <D as NamedDimension<S>>::Dimension;
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __exponent_int {
+    (0) => { $crate::exponents::Z0 };
+    (1) => { $crate::exponents::P1 };
+    (2) => { $crate::exponents::P2 };
+    (3) => { $crate::exponents::P3 };
+    (4) => { $crate::exponents::P4 };
+    (-1) => { $crate::exponents::N1 };
+    (-2) => { $crate::exponents::N2 };
+    (-3) => { $crate::exponents::N3 };
+    (-4) => { $crate::exponents::N4 };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __base_exponents {
+    (length, $e:ty) => { $crate::exponents::Dim<$e, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0> };
+    (mass, $e:ty) => { $crate::exponents::Dim<$crate::exponents::Z0, $e, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0> };
+    (time, $e:ty) => { $crate::exponents::Dim<$crate::exponents::Z0, $crate::exponents::Z0, $e, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0> };
+    (current, $e:ty) => { $crate::exponents::Dim<$crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $e, $crate::exponents::Z0, $crate::exponents::Z0> };
+    (temperature, $e:ty) => { $crate::exponents::Dim<$crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $e, $crate::exponents::Z0> };
+    (angle, $e:ty) => { $crate::exponents::Dim<$crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $crate::exponents::Z0, $e> };
+}
+
+/// Expands a list like `[mass: 1, length: 2, time: -2]` into the matching [`Dim`] type.
+/// Base dimensions that are not listed have an exponent of zero.
+///
+/// # Examples
+///
+/// ```
+/// use shrewnit::exponents::{Exponents, Dim, P1, N1, Z0};
+///
+/// type Velocity = shrewnit::exponents!(length: 1, time: -1);
+/// assert_eq!(<Velocity as Exponents>::EXPONENTS, <Dim<P1, Z0, N1, Z0, Z0, Z0> as Exponents>::EXPONENTS);
+/// ```
+#[macro_export]
+macro_rules! exponents {
+    () => { $crate::exponents::Dimensionless };
+    ($base:ident: - $n:tt $(, $($rest:tt)*)?) => {
+        $crate::exponents::Prod<
+            $crate::__base_exponents!($base, $crate::__exponent_int!(-$n)),
+            $crate::exponents!($($($rest)*)?)
+        >
+    };
+    ($base:ident: $n:tt $(, $($rest:tt)*)?) => {
+        $crate::exponents::Prod<
+            $crate::__base_exponents!($base, $crate::__exponent_int!($n)),
+            $crate::exponents!($($($rest)*)?)
+        >
+    };
+}
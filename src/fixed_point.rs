@@ -0,0 +1,90 @@
+//! Fixed point scalars from the [`fixed`](https://docs.rs/fixed) crate, enabled by the `fixed` feature.
+//!
+//! Every fixed point type, like `I16F16`, is a [`Scalar`](crate::Scalar).
+//! Conversions between units multiply and divide with fixed point arithmetic,
+//! so they work on targets without a floating point unit.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use fixed::types::I16F16;
+//!
+//! let distance = I16F16::from_num(10) * Feet;
+//! let time = I16F16::from_num(4) * Seconds;
+//! let speed = distance / time;
+//!
+//! assert!((distance.to::<Millimeters>() - I16F16::from_num(3048)).abs() < 0.1);
+//! assert_eq!(speed.to::<MetersPerSecond>(), I16F16::lit("0.762"));
+//! ```
+//!
+//! With the `const_operators` feature, the const API works with fixed point scalars as well.
+//! Its constants are calculated by the compiler.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! # #[cfg(feature = "const_operators")]
+//! # {
+//! use fixed::types::I16F16;
+//!
+//! const STEP: Length<I16F16> = <Meters as One<I16F16, _>>::ONE.mul_scalar(I16F16::lit("0.25"));
+//! const TRACK: Length<I16F16> = STEP.mul_scalar(I16F16::lit("12"));
+//!
+//! assert_eq!(TRACK.to::<Centimeters>(), I16F16::from_num(300));
+//! # }
+//! ```
+//!
+//! Fixed point numbers have a limited range and precision.
+//! Conversion factors and their reciprocals are calculated by the compiler and rounded to the nearest value the type can hold.
+//! Factors too large for the type are applied as exact fractions with integer arithmetic,
+//! so a conversion only fails if its result doesn't fit.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use fixed::types::{I16F16, I8F24};
+//! use shrewnit::units::ConversionError;
+//!
+//! // A microgram is less than the smallest I16F16 in kilograms.
+//! let dose = I16F16::from_num(1) * Micrograms;
+//! assert_eq!(dose.to::<Kilograms>(), I16F16::from_num(0));
+//! assert_eq!(dose.try_to::<Micrograms>(), Ok(I16F16::from_num(0)));
+//!
+//! let pulse = I8F24::lit("0.1") * Milliseconds;
+//! assert!((pulse.try_to::<Microseconds>().unwrap() - I8F24::from_num(100)).abs() < 0.1);
+//! assert_eq!((I8F24::from_num(1) * Seconds).try_to::<Microseconds>(), Err(ConversionError::Overflow));
+//! ```
+
+/// Rounds a float to the bits of a fixed point number with `frac_nbits` fractional bits, for use in constants.
+///
+/// Panics if the bits are outside of `min..=max`, which fails to compile when evaluating a constant.
+#[doc(hidden)]
+pub const fn __fixed_bits(value: f64, frac_nbits: u32, min: f64, max: f64) -> f64 {
+    let mut scaled = value;
+    let mut bit = 0;
+    while bit < frac_nbits {
+        scaled *= 2.0;
+        bit += 1;
+    }
+
+    let rounded = if scaled < 0.0 {
+        scaled - 0.5
+    } else {
+        scaled + 0.5
+    };
+    // Casting to an integer truncates, which finishes rounding to the nearest whole number.
+    if rounded <= min - 1.0 || rounded >= max + 1.0 {
+        panic!("constant is out of range for the fixed point type");
+    }
+    rounded
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __fixed_const {
+    ($fixed:ident, $inner:ident, $value:expr) => {
+        $crate::__fixed::$fixed::<Frac>::from_bits($crate::fixed_point::__fixed_bits(
+            $value,
+            $crate::__fixed::$fixed::<Frac>::FRAC_NBITS,
+            core::$inner::MIN as f64,
+            core::$inner::MAX as f64,
+        ) as $inner)
+    };
+}
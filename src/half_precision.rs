@@ -0,0 +1,33 @@
+//! Half precision floats from the [`half`](https://docs.rs/half) crate, enabled by the `half` feature.
+//!
+//! [`f16`](half::f16) and [`bf16`](half::bf16) are [`Scalar`](crate::Scalar)s,
+//! so they work with every dimension, including ones declared with [`dimension!`](crate::dimension).
+//! They hold about three significant digits, so conversions are only as precise as that.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use half::{bf16, f16};
+//!
+//! let distance = f16::from_f32(2.0) * Kilometers;
+//! assert_eq!(distance.to::<Meters>(), f16::from_f32(2000.0));
+//!
+//! let mass = bf16::from_f32(3.0) * Kilograms;
+//! assert!((mass.to::<Pounds>().to_f32() - 6.614).abs() < 0.05);
+//! ```
+//!
+//! With the `const_operators` feature, units provide their [`One`](crate::One) and [`Affine`](crate::Affine)
+//! constants for half precision floats, like they do for the primitive scalars.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! # #[cfg(feature = "const_operators")]
+//! # {
+//! use half::f16;
+//!
+//! const INCH: Length<f16> = <Inches as One<f16, _>>::ONE;
+//! const FREEZING: Temperature<f16> = <Fahrenheit as Affine<f16, _>>::ZERO;
+//!
+//! assert!((INCH.to::<Millimeters>().to_f32() - 25.4).abs() < 0.05);
+//! assert!((FREEZING.to::<Kelvin>().to_f32() - 255.4).abs() < 0.5);
+//! # }
+//! ```
@@ -0,0 +1,262 @@
+//! Scalars bounding a value between a lower and an upper bound.
+//!
+//! [`Interval`] is a [`Scalar`](crate::Scalar) holding every value a quantity could have, like a part within its tolerance.
+//! Arithmetic, unit conversions and the relations between dimensions give bounds that contain every possible result,
+//! so intervals stack worst case tolerances.
+//!
+//! ```
+//! use shrewnit::{interval::Interval, prelude::*};
+//!
+//! // Three plates, each 10 ± 0.1 mm thick, stacked into a 30.5 mm slot.
+//! let plate = Interval::around(10.0f64, 0.1) * Millimeters;
+//! let stack = plate + plate + plate;
+//! let slot = Interval::point(30.5f64) * Millimeters;
+//!
+//! let clearance = (slot - stack).to::<Millimeters>();
+//! assert!(clearance.contains(0.2) && clearance.contains(0.8));
+//! assert!(clearance.min() > 0.19 && clearance.max() < 0.81);
+//! assert!((clearance.width() - 0.6).abs() < 1e-9);
+//!
+//! assert_eq!(format!("{:.2}", stack.display_in::<Millimeters>()), "[29.70, 30.30] mm");
+//! ```
+//!
+//! Results of floating point arithmetic are rounded, so the bounds of every result are moved outward,
+//! past the rounded result, to keep containing the exact one. Conversion factors are taken as exact.
+
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use num_traits::{float::FloatCore, FromPrimitive, NumCast, ToPrimitive};
+
+use crate::display::{write_number, write_padded_numbers};
+
+/// A value known to be between a lower and an upper bound, inclusive.
+///
+/// Values converted from primitives, like conversion factors, are points with equal bounds.
+/// Converting to primitives gives the midpoint of the interval.
+///
+/// ```
+/// use shrewnit::interval::Interval;
+///
+/// let width = Interval::new(2.0f64, 3.0);
+/// let height = Interval::new(-1.0, 4.0);
+///
+/// let area = width * height;
+/// assert!(area.contains(-3.0) && area.contains(12.0));
+/// assert!(area.min() > -3.001 && area.max() < 12.001);
+///
+/// let either = width.hull(Interval::point(5.0));
+/// assert_eq!(either, Interval::new(2.0, 5.0));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Interval<T> {
+    min: T,
+    max: T,
+}
+
+impl<T: FloatCore> Interval<T> {
+    /// Creates an interval between two bounds, given in either order.
+    #[inline]
+    pub fn new(a: T, b: T) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Creates an interval holding only the given value.
+    #[inline]
+    pub fn point(value: T) -> Self {
+        Self {
+            min: value,
+            max: value,
+        }
+    }
+
+    /// Creates an interval of the values within a tolerance of the given value.
+    #[inline]
+    pub fn around(value: T, tolerance: T) -> Self {
+        let tolerance = tolerance.abs();
+        Self::new(down(value - tolerance), up(value + tolerance))
+    }
+
+    /// Returns the lower bound.
+    #[inline]
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the upper bound.
+    #[inline]
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Returns the value halfway between the bounds.
+    #[inline]
+    pub fn midpoint(&self) -> T {
+        if self.min == self.max {
+            return self.min;
+        }
+        self.min / (T::one() + T::one()) + self.max / (T::one() + T::one())
+    }
+
+    /// Returns the distance between the bounds.
+    #[inline]
+    pub fn width(&self) -> T {
+        up(self.max - self.min)
+    }
+
+    /// Whether the value is within the bounds.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    #[inline]
+    pub fn hull(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Creates an interval from bounds calculated with rounding, moving them outward.
+    #[inline]
+    fn rounded(min: T, max: T) -> Self {
+        Self {
+            min: down(min),
+            max: up(max),
+        }
+    }
+}
+
+/// Moves a rounded result down past the exact result, by at least a unit in the last place.
+#[inline]
+fn down<T: FloatCore>(value: T) -> T {
+    if !value.is_finite() {
+        return value;
+    }
+    value - (value.abs() * T::epsilon() + T::min_positive_value())
+}
+
+/// Moves a rounded result up past the exact result, by at least a unit in the last place.
+#[inline]
+fn up<T: FloatCore>(value: T) -> T {
+    if !value.is_finite() {
+        return value;
+    }
+    value + (value.abs() * T::epsilon() + T::min_positive_value())
+}
+
+impl<T: FloatCore> Add for Interval<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::rounded(self.min + rhs.min, self.max + rhs.max)
+    }
+}
+
+impl<T: FloatCore> Sub for Interval<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::rounded(self.min - rhs.max, self.max - rhs.min)
+    }
+}
+
+impl<T: FloatCore> Mul for Interval<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let products = [
+            self.min * rhs.min,
+            self.min * rhs.max,
+            self.max * rhs.min,
+            self.max * rhs.max,
+        ];
+        let min = products.iter().fold(T::infinity(), |min, &p| min.min(p));
+        let max = products.iter().fold(T::neg_infinity(), |max, &p| max.max(p));
+        Self::rounded(min, max)
+    }
+}
+
+impl<T: FloatCore> Div for Interval<T> {
+    type Output = Self;
+    /// Divides by an interval, giving every real number if the divisor contains zero.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(T::zero()) {
+            return Self {
+                min: T::neg_infinity(),
+                max: T::infinity(),
+            };
+        }
+        let quotients = [
+            self.min / rhs.min,
+            self.min / rhs.max,
+            self.max / rhs.min,
+            self.max / rhs.max,
+        ];
+        let min = quotients.iter().fold(T::infinity(), |min, &q| min.min(q));
+        let max = quotients.iter().fold(T::neg_infinity(), |max, &q| max.max(q));
+        Self::rounded(min, max)
+    }
+}
+
+impl<T: FloatCore> Neg for Interval<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            min: -self.max,
+            max: -self.min,
+        }
+    }
+}
+
+impl<T: FloatCore> FromPrimitive for Interval<T> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        <T as NumCast>::from(n).map(Self::point)
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        <T as NumCast>::from(n).map(Self::point)
+    }
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        <T as NumCast>::from(n).map(Self::point)
+    }
+}
+
+impl<T: FloatCore> ToPrimitive for Interval<T> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.midpoint().to_i64()
+    }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.midpoint().to_u64()
+    }
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.midpoint().to_f64()
+    }
+}
+
+impl<T: FloatCore + fmt::Display> fmt::Display for Interval<T> {
+    /// Writes `[min, max]`, applying the sign and precision flags to both bounds.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_padded_numbers(f, |w, sign_plus, precision| {
+            w.write_char('[')?;
+            write_number(w, &self.min, sign_plus, precision)?;
+            w.write_str(", ")?;
+            write_number(w, &self.max, sign_plus, precision)?;
+            w.write_char(']')
+        })
+    }
+}
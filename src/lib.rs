@@ -54,6 +54,42 @@
 //! let acceleration = change_in_velocity / time;
 //! ```
 //!
+//! Products can be taken in either order, and dividing a product by one of its factors gives the other factor back.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let angle = 2.0f64 * Radians;
+//! let torque = 3.0f64 * NewtonMetersPerRadian;
+//! let energy = angle * torque;
+//!
+//! assert_eq!(energy / angle, torque);
+//! assert_eq!(energy / torque, angle);
+//! ```
+//!
+//! Dividing two quantities of the same dimension gives a dimensionless `Ratio`,
+//! and multiplying a quantity by a `Ratio` keeps its dimension.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let efficiency = (450.0f64 * Watts) / (500.0f64 * Watts);
+//!
+//! assert_eq!(efficiency.to::<Percent>(), 90.0);
+//!
+//! let output = efficiency * (2.0 * Horsepower);
+//! ```
+//!
+//! Absolute temperatures are points on a scale rather than amounts, so they can't be added together.
+//! Subtracting two temperatures gives a `TemperatureInterval`, which can be added to or subtracted from a temperature.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let morning = 15.0 * Celsius;
+//! let afternoon = 68.0 * Fahrenheit;
+//!
+//! let warming: TemperatureInterval = afternoon - morning;
+//! let evening = afternoon + warming;
+//! ```
+//!
 //! If you attempt an unsupported operation on two quantities you will get a compile error like this:
 //!
 //! ```text
@@ -71,6 +107,147 @@
 //!              `Length<S>` implements `Mul`
 //! ```
 //!
+//! ### Generic quantities
+//!
+//! Every dimension provided by Shrewnit also knows its exponents over the base dimensions
+//! (length, mass, time, current, temperature and angle).
+//! Converting quantities into the generic [`Quantity`](quantity::Quantity) type lets the compiler work out
+//! the dimension of any product or quotient, even ones without a dedicated dimension type.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use shrewnit::quantity::Quantity;
+//!
+//! let impulse = Quantity::from(2.0 * Newtons) * Quantity::from(3.0 * Seconds);
+//! let velocity: LinearVelocity = (impulse / Quantity::from(1.5 * Kilograms)).into();
+//! ```
+//!
+//! ### Runtime dimensions
+//!
+//! Values whose dimension is only known at runtime, like ones read from a config file, can be stored in a `DynQuantity`.
+//! Adding and subtracting checks the dimensions at runtime, and `TryFrom` converts back into a dimension type.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use core::convert::TryFrom;
+//! use shrewnit::dynamic::DynQuantity;
+//!
+//! let energy = DynQuantity::from(500.0f64 * Joules);
+//! let time = DynQuantity::from(2.0f64 * Seconds);
+//!
+//! let power: Power = Power::try_from(energy / time).unwrap();
+//! assert!(energy.checked_add(time).is_err());
+//! ```
+//!
+//! ### Storage units
+//!
+//! Quantities are stored in the canonical unit of their dimension by default, which loses precision with integer scalars:
+//! `Length<i32>` stores whole meters.
+//! The second type parameter of a dimension chooses another unit to store the value in,
+//! so integer quantities can be exact in a unit of your choice.
+//! Conversions between storage units are explicit, and fail if the value can't be represented exactly.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let wheelbase = Length::<i32, Millimeters>::new(2540);
+//! let track = Length::<i32, Millimeters>::new(1500);
+//! let total = wheelbase + track * 2;
+//!
+//! assert_eq!(total.value(), 5540);
+//! assert_eq!(wheelbase.checked_convert::<Inches>().map(|inches| inches.value()), Some(100));
+//! assert_eq!(track.checked_convert::<Meters>(), None);
+//! ```
+//!
+//! Integer conversions between storage units are done in exact integer arithmetic, even beyond the precision of an `f64`.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let far = Length::<i64, Millimeters>::new(10_000_000_001);
+//! assert_eq!(far.checked_convert::<Meters>(), None);
+//! assert_eq!(Length::<i64, Meters>::new(10_000_000_001).checked_convert::<Millimeters>(), Some(Length::new(10_000_000_001_000)));
+//!
+//! let precise = Length::<i64, Millimeters>::new((1 << 53) + 1);
+//! assert_eq!(precise.checked_convert::<Millimeters>(), Some(precise));
+//! ```
+//!
+//! Quantities stored in other units support addition, subtraction, scaling and formatting.
+//! Convert them to the canonical unit to use them with other dimensions.
+//! `convert` and `try_convert` change the storage unit even when the value isn't exact, truncating integer results.
+//! `convert` panics if the result doesn't fit in the scalar type, while `try_convert` returns an error.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use shrewnit::units::ConversionError;
+//!
+//! let track = Length::<i32, Millimeters>::new(1500);
+//! assert_eq!(track.convert::<Centimeters>().value(), 150);
+//! assert_eq!(track.convert::<Meters>(), 1 * Meters);
+//! assert_eq!(Length::<u8, Millimeters>::new(200).try_convert::<Meters>(), Ok(Length::new(0)));
+//! assert_eq!(Length::<u8, Meters>::new(1).try_convert::<Millimeters>(), Err(ConversionError::Overflow));
+//! ```
+//!
+//! ### Overflow
+//!
+//! Like the scalars they hold, integer quantities panic on overflow in debug builds and wrap in release builds.
+//! Every dimension has checked, saturating and wrapping versions of addition, subtraction and scaling,
+//! and `checked_to` converts to another unit without panicking when the result doesn't fit in the scalar type.
+//! Converting by multiplying with a unit, with `to` or with `ScalarExt` panics in that case instead.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let position = Length::<i16, Millimeters>::new(30_000);
+//! let step = Length::<i16, Millimeters>::new(5_000);
+//!
+//! assert_eq!(position.checked_add(step), None);
+//! assert_eq!(position.saturating_add(step).value(), i16::max_value());
+//! assert_eq!(step.checked_mul_scalar(4).map(|length| length.value()), Some(20_000));
+//!
+//! let uptime = 5_000_000u32 * Seconds;
+//! assert_eq!(uptime.checked_to::<Milliseconds>(), None);
+//! assert_eq!(uptime.checked_to::<Hours>(), Some(1388));
+//! ```
+//!
+//! `try_to` and `try_from_scalar` also explain why a conversion failed, with a `ConversionError`.
+//! Units created by `dimension!` and `simple_unit!` never panic when converted this way.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use shrewnit::units::ConversionError;
+//!
+//! assert_eq!((3u8 * Meters).try_to::<Millimeters>(), Err(ConversionError::Overflow));
+//! assert_eq!((250u16 * Kelvin).try_to::<Celsius>(), Err(ConversionError::Overflow));
+//! assert_eq!(Length::<u8>::try_from_scalar::<Inches>(200).map(|length| length.to::<Meters>()), Ok(5));
+//! ```
+//!
+//! ### Durations
+//!
+//! `Time` converts to and from `core::time::Duration`, and durations can be added to and subtracted from `f64` and `f32` times.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use core::{convert::TryFrom, time::Duration};
+//!
+//! let elapsed = Time::<f64>::from(Duration::from_millis(1500));
+//! let remaining = 5.0f64 * Seconds - Duration::from_secs(2);
+//!
+//! let timeout = Duration::try_from(remaining).unwrap();
+//! assert!(Duration::try_from(-1.0f64 * Seconds).is_err());
+//! ```
+//!
+//! ### Powers and roots
+//!
+//! The `Powers` trait squares, cubes and takes roots of quantities,
+//! giving back the dimension type with the matching exponents.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let side = 3.0f64 * Meters;
+//! let area: Area = side.squared();
+//!
+//! // Roots need the `std` or `libm` feature.
+//! // let side: Length = area.sqrt();
+//! ```
+//!
 //! ## Accessing the Value
 //!
 //! To get the value of a dimension, use the `to` function.
@@ -83,6 +260,79 @@
 //! println!("{}", time.to::<Minutes>());
 //! ```
 //!
+//! Quantities can also be displayed with the symbol of their canonical unit, or in any other unit with `display_in`.
+//! The alternate flag prints the name of the unit instead.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let time = 5.0f64 * Seconds;
+//! println!("{}", time); // 5 s
+//! println!("{:.2}", time.display_in::<Minutes>()); // 0.08 min
+//! println!("{:#}", time.display_in::<Milliseconds>()); // 5000 milliseconds
+//! ```
+//!
+//! `humanize` picks the unit that gives a quantity a readable magnitude,
+//! choosing from metric units by default or from the units of another system with `humanize_in`.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use shrewnit::units::UnitSystem;
+//!
+//! let distance = 1200.0f64 * Meters;
+//! println!("{}", distance.humanize()); // 1.2 km
+//! println!("{:.2}", distance.humanize_in(UnitSystem::Imperial)); // 0.75 mi
+//! println!("{}", (0.25f64 * Seconds).humanize()); // 250 ms
+//! ```
+//!
+//! Quantities can be split between several units, from largest to smallest, with `to_compound` and `display_compound`.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let height = 63.0f64 * Inches;
+//! let (feet, inches) = height.to_compound((Feet, Inches));
+//! println!("{}", height.display_compound((Feet, Inches))); // 5 ft 3 in
+//! ```
+//!
+//! ## Parsing Quantities
+//!
+//! Every dimension implements `FromStr`. Units can be written as their symbol or their full name.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let length: Length = "5 ft".parse().unwrap();
+//! let speed: LinearVelocity = "3.2 km/h".parse().unwrap();
+//! let temperature: Temperature = "20 degrees Celsius".parse().unwrap();
+//! let time: Time = "1 h 23 min 4 s".parse().unwrap();
+//!
+//! assert!("5 kg".parse::<Length>().is_err());
+//! ```
+//!
+//! ## Listing Units
+//!
+//! Every dimension keeps a static table of its units, with their symbols, names and conversion factors.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! for unit in Length::units() {
+//!     println!("{} ({})", unit.plural, unit.symbol);
+//! }
+//! ```
+//!
+//! Units can also be looked up by their symbol or name at runtime, which is useful when the unit comes from user input.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! let inches = shrewnit::convert(5.0, "ft", "in").unwrap();
+//!
+//! let length = Length::from_unit_name(5.0, "feet").unwrap();
+//! let meters = length.to_unit_name("m").unwrap();
+//!
+//! assert!(length.to_unit_name("kg").is_err());
+//! assert!(Length::<u8>::from_unit_name(10, "km").is_err());
+//! ```
+//!
+//! Custom dimensions can be included in these lookups with [`convert_in`](units::convert_in) and their `UNIT_TABLE`.
+//!
 //! ## Using Shrewnit in const contexts
 //!
 //! With the `const_operators` feature enabled (it's on by default), you can use Shrewnit entirely in const!
@@ -109,6 +359,16 @@
 //! const INCHES: f64 = DISTANCE.to::<Inches>();
 //! ```
 //!
+//! Units with an offset, like `Celsius`, implement `Affine` instead of `One`.
+//! Use `from_affine` and `to_affine` to convert them in const.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! const BOILING: Temperature = Temperature::<f64>::from_affine::<Celsius>(100.0);
+//!
+//! const FAHRENHEIT: f64 = BOILING.to_affine::<Fahrenheit>();
+//! ```
+//!
 //! Note that none of this works in const if the dimension type is generic.
 //! Due to Rust limitations, all const functions are implemented on dimension types individually.
 //!
@@ -126,17 +386,28 @@
 //!     pub MyCustomDimension {
 //!         // Shrewnit uses standard SI units as canonical units. This isn't required. Do whatever you feel like.
 //!         canonical: MyStandardSiUnit,
+//!         // Optional exponents of the base dimensions, used to convert to and from `Quantity`.
+//!         exponents: [length: 1, time: -1],
 //!
 //!         // Conversion can be read as "one MyStandardSiUnit per canonical unit"
-//!         MyStandardSiUnit: 1.0 per canonical,
+//!         // The optional brackets hold the symbol, singular name, plural name and any aliases of the unit.
+//!         MyStandardSiUnit ["mssu", "standard unit", "standard units"]: 1.0 per canonical,
+//!         // Units can be tagged with the systems of units they belong to, used by `humanize`.
+//!         MyImperialUnit in imperial: per 0.3 canonical,
 //!         // Conversion can be read as "two MyHalfUnits per canonical unit"
 //!         MyHalfUnit: 2.0 per canonical,
 //!         // Conversion can be read as "one MyDoubleUnits per two canonical units"
 //!         MyDoubleUnit: per 2.0 canonical,
+//!         // Conversion can be read as "one MyOffsetUnit per canonical unit, with zero at 10 canonical units"
+//!         MyOffsetUnit: 1.0 per canonical offset 10.0,
 //!     } where {
 //!         // Optional operations block.
 //!         // Self </ or *> <other or same dimension type> => <output dimension type> in <output units>
 //!         Self / SomeOtherDimension => ACompletelyDifferentDimension in SomeUnit,
+//!         // `<=>` also implements the commuted product and both inverse quotients of a relation,
+//!         // in this case `YetAnotherDimension * Self`, `SomeProduct / Self` and `SomeProduct / YetAnotherDimension`.
+//!         // Declare each relation with `<=>` only once. All three dimensions must be defined in the same crate.
+//!         Self * YetAnotherDimension <=> SomeProduct in SomeProductUnit,
 //!     }
 //! );
 //! ```
@@ -164,10 +435,15 @@
 //! ```
 //! # use shrewnit::prelude::*;
 //! shrewnit::simple_unit!(
-//!     pub MyCustomUnitOfLength of dimension Length = per 2.0 canonical
+//!     pub MyCustomUnitOfLength ["mcu", "custom unit", "custom units"] of dimension Length = per 2.0 canonical
 //! );
 //! ```
 //!
+//! The optional brackets hold the symbol, singular name, plural name and any aliases of the unit.
+//! They are used when parsing and displaying quantities, and are available through the `UnitInfo` trait.
+//!
+//! Units created by `simple_unit!` also implement `TryUnitOf`, which is needed by `try_to` and `checked_to`.
+//!
 //! The conversions will be in terms of the dimension's canonical unit. The canonical unit for all Shrewnit measures are the standard SI unit. If you do not know what this is, go to the definition of the dimension. The canonical unit is the one marked with `canonical: <unit>`.
 //!
 //! ```ignore
@@ -200,8 +476,9 @@
 //!
 //! > What is the MSRV of Shrewnit?
 //!
-//! With the `const_operators` feature disabled, the MSRV is version 1.33 (the oldest version supported by any unit library!).
+//! With the `const_operators` feature disabled, the MSRV is version 1.34 (the oldest version supported by any unit library!).
 //! However, the feature uses significantly more recent features.
+//! The `fixed`, `decimal`, `half` and `complex` features need whichever version the crates they add require.
 //!
 //! > Where does the name come from?
 //!
@@ -211,7 +488,14 @@
 //!
 //! Shrewnit always depends on one crate: `num-traits`.
 //! If the `const_operators` feature is enabled, `paste` will also be added to the dependencies.
-//! Despite this, Shrewnit is 100% Rust, `no_std`, libm, and alloc free!
+//! The optional `fixed` feature adds the `fixed` crate for fixed point scalars, like `I16F16`,
+//! the optional `decimal` feature adds the `rust_decimal` crate for decimal scalars,
+//! the optional `half` feature adds the `half` crate for half precision floats,
+//! and the optional `complex` feature adds the `num-complex` crate for complex scalars.
+//! Despite this, Shrewnit is 100% Rust, `no_std`, and alloc free!
+//! Shrewnit only needs float math for roots and powers of quantities and for `Measured` uncertainties,
+//! which are enabled by either the `std` feature or the `libm` feature.
+//! `Interval` tolerances only need `FloatCore`, so they work without either feature.
 //!
 //! > How will this library effect the trout population?
 //!
@@ -220,30 +504,70 @@
 //! That said, this library is going to compile much faster than any library that depends on `bindgen`.
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "complex")]
+pub mod complex;
+pub mod compound;
+#[cfg(feature = "decimal")]
+pub mod decimal;
 pub mod dimensions;
-use core::{
-    iter::Sum,
-    ops::{Add, Div, Mul, Sub},
-};
+pub mod display;
+pub mod duration;
+pub mod dynamic;
+pub mod exponents;
+#[cfg(feature = "fixed")]
+pub mod fixed_point;
+#[cfg(feature = "half")]
+pub mod half_precision;
+pub mod interval;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod measured;
+pub mod parse;
+pub mod powers;
+pub mod quantity;
+pub mod units;
+use core::ops::{Add, Div, Mul, Sub};
 
 pub use dimensions::*;
-use num_traits::{AsPrimitive, FromPrimitive};
+pub use units::convert;
+use num_traits::{FromPrimitive, ToPrimitive};
 
 pub mod prelude {
     pub use crate::dimensions::*;
-    pub use crate::{Dimension, One, ScalarExt};
+    pub use crate::powers::Powers;
+    pub use crate::{Affine, Dimension, One, ScalarExt};
 }
 
 #[doc(hidden)]
 #[cfg(feature = "const_operators")]
 pub use paste::paste as __paste;
+#[doc(hidden)]
+pub use num_traits as __num_traits;
+#[doc(hidden)]
+#[cfg(feature = "fixed")]
+pub use ::fixed as __fixed;
+#[doc(hidden)]
+#[cfg(feature = "decimal")]
+pub use ::rust_decimal as __rust_decimal;
+#[doc(hidden)]
+#[cfg(feature = "half")]
+pub use ::half as __half;
+#[doc(hidden)]
+#[cfg(feature = "complex")]
+pub use ::num_complex as __num_complex;
 
 /// A set of requirements for a scalar type to be used in measures.
 ///
-/// This trait is automatically implemented for any type that implements `FromPrimitive`, `Clone`, and the basic arithmetic operations.
+/// This trait is automatically implemented for any type that implements `FromPrimitive`, `ToPrimitive`, `Clone`, and the basic arithmetic operations.
+///
+/// Conversion factors are applied with the scalar's own arithmetic if it can hold fractions,
+/// so types like decimals, rationals and fixed point numbers don't lose precision to an `f64`.
+/// Integers are converted through an `f64` and truncated.
 pub trait Scalar:
     FromPrimitive
-    + AsPrimitive<f64>
+    + ToPrimitive
     + Clone
     + Mul<Output = Self>
     + Div<Output = Self>
@@ -253,7 +577,7 @@ pub trait Scalar:
 }
 impl<
         T: FromPrimitive
-            + AsPrimitive<f64>
+            + ToPrimitive
             + Clone
             + Mul<Output = T>
             + Div<Output = T>
@@ -267,7 +591,15 @@ impl<
 pub trait Dimension<S: Scalar = f64> {
     type CanonicalUnit: UnitOf<S, Self>;
 
+    /// The units declared with this dimension.
+    const UNITS: &'static [units::UnitDescriptor] = &[];
+
     /// Converts the dimension to the given unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can't be represented by the scalar type, like 3 meters in `u8` millimeters.
+    /// Use [`try_to`](Dimension::try_to) to handle that case.
     #[inline]
     fn to<U: UnitOf<S, Self>>(&self) -> S
     where
@@ -276,6 +608,91 @@ pub trait Dimension<S: Scalar = f64> {
         U::from_canonical(self.canonical())
     }
 
+    /// Displays the dimension in the given unit.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    ///
+    /// let distance = 3.0f64 * Miles;
+    /// println!("{:.1}", distance.display_in::<Kilometers>());
+    /// ```
+    #[inline]
+    fn display_in<U: UnitOf<S, Self> + units::UnitInfo>(&self) -> display::DisplayIn<S, U>
+    where
+        Self: Sized,
+    {
+        display::DisplayIn::new(self.to::<U>())
+    }
+
+    /// Splits the dimension into a whole number of each of the given units but the last, and a remainder in the last.
+    ///
+    /// The units are given as a tuple, from largest to smallest.
+    /// Every part of a negative quantity is negative.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    ///
+    /// let weight = 90.0f64 * Kilograms;
+    /// let (stones, pounds) = weight.to_compound((Stones, Pounds));
+    /// assert_eq!(stones, 14.0);
+    /// assert!((pounds - 2.416).abs() < 0.001);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a part can't be represented by the scalar type, like 656 feet in a `u8`.
+    /// Use [`try_to_compound`](Dimension::try_to_compound) to handle that case.
+    #[inline]
+    fn to_compound<U: compound::CompoundUnits<S, Self>>(&self, units: U) -> U::Parts
+    where
+        Self: Sized,
+    {
+        self.try_to_compound(units).unwrap()
+    }
+
+    /// Splits the dimension like [`to_compound`](Dimension::to_compound),
+    /// failing if a part can't be represented by the scalar type.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    /// use shrewnit::units::ConversionError;
+    ///
+    /// let height = 2u8 * Meters;
+    /// assert_eq!(height.try_to_compound((Feet, Inches)), Ok((6, 6)));
+    ///
+    /// let height = 200u8 * Meters;
+    /// assert_eq!(height.try_to_compound((Feet, Inches)), Err(ConversionError::Overflow));
+    /// ```
+    #[inline]
+    fn try_to_compound<U: compound::CompoundUnits<S, Self>>(
+        &self,
+        _units: U,
+    ) -> Result<U::Parts, units::ConversionError>
+    where
+        Self: Sized,
+    {
+        compound::__to_compound::<S, Self, U>(self)
+    }
+
+    /// Displays the dimension split between the given units, like `5 ft 3 in`.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    ///
+    /// let time = 5000.0f64 * Seconds;
+    /// println!("{}", time.display_compound((Hours, Minutes, Seconds))); // 1 h 23 min 20 s
+    /// ```
+    #[inline]
+    fn display_compound<U: compound::CompoundUnits<S, Self>>(
+        &self,
+        _units: U,
+    ) -> compound::DisplayCompound<S, Self, U>
+    where
+        Self: Sized,
+    {
+        compound::DisplayCompound::new(self.canonical())
+    }
+
     /// Creates a new dimension from the given scalar and unit.
     ///
     /// # Note
@@ -288,6 +705,11 @@ pub trait Dimension<S: Scalar = f64> {
     /// let quantity = 30.0f32 * Meters;
     /// let quantity = 30.0f32.meters();
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the canonical value can't be represented by the scalar type, like 3 kilometers in `u8` meters.
+    /// Use [`try_from_scalar`](Dimension::try_from_scalar) to handle that case.
     #[inline]
     fn from_scalar<U: UnitOf<S, Self>>(value: S) -> Self
     where
@@ -296,6 +718,66 @@ pub trait Dimension<S: Scalar = f64> {
         Self::from_canonical(U::to_canonical(value))
     }
 
+    /// Converts the dimension to the given unit, returning `None` if the result doesn't fit in the scalar type.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    ///
+    /// let distance = 3i32 * Meters;
+    /// assert_eq!(distance.checked_to::<Millimeters>(), Some(3000));
+    ///
+    /// let distance = 3u8 * Meters;
+    /// assert_eq!(distance.checked_to::<Millimeters>(), None);
+    /// ```
+    #[inline]
+    fn checked_to<U: CheckedUnitOf<S, Self>>(&self) -> Option<S>
+    where
+        Self: Sized,
+    {
+        U::checked_from_canonical(self.canonical())
+    }
+
+    /// Converts the dimension to the given unit, failing if the result can't be represented by the scalar type.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    /// use shrewnit::units::ConversionError;
+    ///
+    /// let temperature = 300u16 * Kelvin;
+    /// assert_eq!(temperature.try_to::<Celsius>(), Ok(26));
+    /// assert_eq!(temperature.try_to::<Fahrenheit>(), Ok(80));
+    ///
+    /// let temperature = 250u16 * Kelvin;
+    /// assert_eq!(temperature.try_to::<Celsius>(), Err(ConversionError::Overflow));
+    /// ```
+    #[inline]
+    fn try_to<U: TryUnitOf<S, Self>>(&self) -> Result<S, units::ConversionError>
+    where
+        Self: Sized,
+    {
+        U::try_from_canonical(self.canonical())
+    }
+
+    /// Creates a new dimension from the given scalar and unit,
+    /// failing if the canonical value can't be represented by the scalar type.
+    #[inline]
+    fn try_from_scalar<U: TryUnitOf<S, Self>>(value: S) -> Result<Self, units::ConversionError>
+    where
+        Self: Sized,
+    {
+        U::try_to_canonical(value).map(Self::from_canonical)
+    }
+
+    /// Creates a new dimension from the given scalar and unit,
+    /// returning `None` if the canonical value doesn't fit in the scalar type.
+    #[inline]
+    fn checked_from_scalar<U: CheckedUnitOf<S, Self>>(value: S) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        U::checked_to_canonical(value).map(Self::from_canonical)
+    }
+
     /// Returns the canonical representation of the dimension.
     fn canonical(&self) -> S;
     /// Creates a new dimension from the canonical representation.
@@ -309,7 +791,7 @@ pub trait Dimension<S: Scalar = f64> {
 /// # Note
 ///
 /// Do NOT implement this for units with *affine* transformations.
-/// An example of this would be Fahrenheit in the tempurature dimension.
+/// An example of this would be Fahrenheit in the tempurature dimension, which implements [`Affine`] instead.
 pub trait One<S: Scalar, D: Dimension<S>>: UnitOf<S, D> {
     /// The dimension with a value of 1.0 in this unit.
     const ONE: D;
@@ -317,9 +799,30 @@ pub trait One<S: Scalar, D: Dimension<S>>: UnitOf<S, D> {
     const ONE_CANONICAL: S;
 }
 
+/// Implemented for units with affine transformations to the canonical unit of their dimension,
+/// such as Celsius in the temperature dimension.
+///
+/// This trait is automatically implemented by the [`simple_unit!`](simple_unit) macro for units declared with an `offset` or `zero at`.
+/// Quantities can be converted to and from these units in const with `to_affine` and `from_affine`.
+pub trait Affine<S: Scalar, D: Dimension<S>>: UnitOf<S, D> {
+    /// The dimension with a value of 0.0 in this unit.
+    const ZERO: D;
+    /// The amount of canonical units that makes up a difference of one of this unit.
+    const ONE_DIFFERENCE: S;
+    /// The amount of this unit that makes up a difference of one canonical unit.
+    const ONE_CANONICAL: S;
+}
+
 /// Denotes that a type is a unit of a dimension.
 ///
 /// This trait provides functionality for converting to and from the canonical unit of a dimension.
+///
+/// # Panics
+///
+/// The conversions of units declared with [`dimension!`](dimension) or [`simple_unit!`](simple_unit)
+/// panic if the result can't be represented by the scalar type, like 3 meters in `u8` millimeters.
+/// These conversions are used by multiplying a scalar by a unit, [`Dimension::to`] and the `ScalarExt` methods,
+/// so use [`TryUnitOf`] through [`Dimension::try_to`] and [`Dimension::try_from_scalar`] to handle that case.
 pub trait UnitOf<S: Scalar, M: Dimension<S> + ?Sized> {
     /// Converts a scalar value from the canonical unit to unit of `Self`.
     fn from_canonical(canonical: S) -> S;
@@ -327,6 +830,38 @@ pub trait UnitOf<S: Scalar, M: Dimension<S> + ?Sized> {
     fn to_canonical(converted: S) -> S;
 }
 
+/// A unit whose conversions can fail, instead of panicking, when the result doesn't fit in the scalar type.
+///
+/// This trait is automatically implemented by the [`simple_unit!`](simple_unit) macro.
+pub trait TryUnitOf<S: Scalar, M: Dimension<S> + ?Sized>: UnitOf<S, M> {
+    /// Converts a scalar value from the canonical unit to the unit of `Self`.
+    fn try_from_canonical(canonical: S) -> Result<S, units::ConversionError>;
+    /// Converts a scalar value from the unit of `Self` to the canonical unit.
+    fn try_to_canonical(converted: S) -> Result<S, units::ConversionError>;
+}
+
+/// A unit whose conversions return `None`, instead of panicking, when the result doesn't fit in the scalar type.
+///
+/// This trait is implemented for every unit that implements [`TryUnitOf`].
+pub trait CheckedUnitOf<S: Scalar, M: Dimension<S> + ?Sized>: UnitOf<S, M> {
+    /// Converts a scalar value from the canonical unit to the unit of `Self`,
+    /// returning `None` if the result can't be represented by the scalar type.
+    fn checked_from_canonical(canonical: S) -> Option<S>;
+    /// Converts a scalar value from the unit of `Self` to the canonical unit,
+    /// returning `None` if the result can't be represented by the scalar type.
+    fn checked_to_canonical(converted: S) -> Option<S>;
+}
+impl<S: Scalar, M: Dimension<S> + ?Sized, U: TryUnitOf<S, M>> CheckedUnitOf<S, M> for U {
+    #[inline]
+    fn checked_from_canonical(canonical: S) -> Option<S> {
+        U::try_from_canonical(canonical).ok()
+    }
+    #[inline]
+    fn checked_to_canonical(converted: S) -> Option<S> {
+        U::try_to_canonical(converted).ok()
+    }
+}
+
 #[macro_export]
 #[doc(hidden)]
 #[cfg(not(feature = "const_operators"))]
@@ -340,6 +875,7 @@ macro_rules! __const_conversion_op_imp {
 #[cfg(feature = "const_operators")]
 macro_rules! __const_conversion_op_imp {
     ($self:ident, Self * $rhs:ident => $output:ident in $output_unit:ty, $($scalar:ident),*) => {
+        $crate::__const_conversion_op_fixed_imp!($self, Self * $rhs => $output in $output_unit);
         $crate::__paste! {
             $(
                 impl $self<$scalar> {
@@ -353,6 +889,7 @@ macro_rules! __const_conversion_op_imp {
         }
     };
     ($self:ident, Self / $rhs:ident => $output:ident in $output_unit:ty, $($scalar:ident),*) => {
+        $crate::__const_conversion_op_fixed_imp!($self, Self / $rhs => $output in $output_unit);
         $crate::__paste! {
             $(
                 impl $self<$scalar> {
@@ -372,6 +909,28 @@ macro_rules! __const_conversion_op_imp {
 macro_rules! __measure_conversions {
     {} => {};
     {$self:ty,} => {};
+    ($self:ident, Self * Self <=> $output:ident in $output_unit:ty, $($rest:tt)*) => {
+        $crate::__measure_conversions!($self, Self * $self => $output in $output_unit,);
+        $crate::__measure_conversions!($output, Self / $self => $self in <$self as $crate::Dimension>::CanonicalUnit,);
+
+        $crate::__measure_conversions!($self, $($rest)*);
+    };
+    ($self:ident, Self * $rhs:ident <=> $output:ident in $output_unit:ty, $($rest:tt)*) => {
+        $crate::__measure_conversions!($self, Self * $rhs => $output in $output_unit,);
+        $crate::__measure_conversions!($rhs, Self * $self => $output in $output_unit,);
+        $crate::__measure_conversions!($output, Self / $rhs => $self in <$self as $crate::Dimension>::CanonicalUnit,);
+        $crate::__measure_conversions!($output, Self / $self => $rhs in <$rhs as $crate::Dimension>::CanonicalUnit,);
+
+        $crate::__measure_conversions!($self, $($rest)*);
+    };
+    ($self:ident, Self / $rhs:ident <=> $output:ident in $output_unit:ty, $($rest:tt)*) => {
+        $crate::__measure_conversions!($self, Self / $rhs => $output in $output_unit,);
+        $crate::__measure_conversions!($self, Self / $output => $rhs in <$rhs as $crate::Dimension>::CanonicalUnit,);
+        $crate::__measure_conversions!($rhs, Self * $output => $self in <$self as $crate::Dimension>::CanonicalUnit,);
+        $crate::__measure_conversions!($output, Self * $rhs => $self in <$self as $crate::Dimension>::CanonicalUnit,);
+
+        $crate::__measure_conversions!($self, $($rest)*);
+    };
     ($self:ident, Self * $rhs:ident => $output:ident in $output_unit:ty, $($rest:tt)*) => {
         impl<S: $crate::Scalar> core::ops::Mul<$rhs<S>> for $self<S> {
             type Output = $output<S>;
@@ -434,9 +993,224 @@ macro_rules! __measure_conversions {
 
 #[macro_export]
 #[doc(hidden)]
-macro_rules! __unit_mult_imp {
-    ($unit:ident, $dimension:ident, $($rhs:ident),*) => {
-        $(
+macro_rules! __ratio_conversions {
+    // Every other dimension already implements its operators with `Ratio`.
+    (Ratio) => {
+        impl<S: $crate::Scalar> core::ops::Div<Ratio<S>> for Ratio<S> {
+            type Output = Ratio<S>;
+            fn div(self, rhs: Ratio<S>) -> Ratio<S> {
+                Ratio(self.0 / rhs.0, core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::Mul<Ratio<S>> for Ratio<S> {
+            type Output = Ratio<S>;
+            fn mul(self, rhs: Ratio<S>) -> Ratio<S> {
+                Ratio(self.0 * rhs.0, core::marker::PhantomData)
+            }
+        }
+    };
+    ($name:ident) => {
+        impl<S: $crate::Scalar> core::ops::Div<$name<S>> for $name<S> {
+            type Output = $crate::Ratio<S>;
+            fn div(self, rhs: $name<S>) -> $crate::Ratio<S> {
+                $crate::Dimension::from_canonical(self.0 / rhs.0)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::Mul<$crate::Ratio<S>> for $name<S> {
+            type Output = $name<S>;
+            fn mul(self, rhs: $crate::Ratio<S>) -> $name<S> {
+                $name(self.0 * $crate::Dimension::canonical(&rhs), core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::Div<$crate::Ratio<S>> for $name<S> {
+            type Output = $name<S>;
+            fn div(self, rhs: $crate::Ratio<S>) -> $name<S> {
+                $name(self.0 / $crate::Dimension::canonical(&rhs), core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::Mul<$name<S>> for $crate::Ratio<S> {
+            type Output = $name<S>;
+            fn mul(self, rhs: $name<S>) -> $name<S> {
+                $name($crate::Dimension::canonical(&self) * rhs.0, core::marker::PhantomData)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __dim_additive_imp {
+    ($name:ident) => {
+        impl<S: $crate::Scalar, U> core::ops::Add<$name<S, U>> for $name<S, U> {
+            type Output = $name<S, U>;
+            fn add(self, rhs: $name<S, U>) -> $name<S, U> {
+                $name(self.0 + rhs.0, core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar, U> core::ops::AddAssign<$name<S, U>> for $name<S, U> {
+            fn add_assign(&mut self, rhs: $name<S, U>) {
+                self.0 = self.0.clone() + rhs.0;
+            }
+        }
+        impl<S: $crate::Scalar, U> core::ops::Sub<$name<S, U>> for $name<S, U> {
+            type Output = $name<S, U>;
+            fn sub(self, rhs: $name<S, U>) -> $name<S, U> {
+                $name(self.0 - rhs.0, core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar, U> core::ops::SubAssign<$name<S, U>> for $name<S, U> {
+            fn sub_assign(&mut self, rhs: $name<S, U>) {
+                self.0 = self.0.clone() - rhs.0;
+            }
+        }
+
+        impl<S: $crate::Scalar, U> $name<S, U> {
+            /// Adds two quantities, returning `None` on overflow.
+            #[inline]
+            pub fn checked_add(self, rhs: Self) -> Option<Self>
+            where
+                S: $crate::__num_traits::CheckedAdd,
+            {
+                $crate::__num_traits::CheckedAdd::checked_add(&self.0, &rhs.0).map(|value| $name(value, core::marker::PhantomData))
+            }
+            /// Subtracts two quantities, returning `None` on overflow.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self>
+            where
+                S: $crate::__num_traits::CheckedSub,
+            {
+                $crate::__num_traits::CheckedSub::checked_sub(&self.0, &rhs.0).map(|value| $name(value, core::marker::PhantomData))
+            }
+            /// Adds two quantities, saturating at the bounds of the scalar type.
+            #[inline]
+            pub fn saturating_add(self, rhs: Self) -> Self
+            where
+                S: $crate::__num_traits::SaturatingAdd,
+            {
+                $name($crate::__num_traits::SaturatingAdd::saturating_add(&self.0, &rhs.0), core::marker::PhantomData)
+            }
+            /// Subtracts two quantities, saturating at the bounds of the scalar type.
+            #[inline]
+            pub fn saturating_sub(self, rhs: Self) -> Self
+            where
+                S: $crate::__num_traits::SaturatingSub,
+            {
+                $name($crate::__num_traits::SaturatingSub::saturating_sub(&self.0, &rhs.0), core::marker::PhantomData)
+            }
+            /// Adds two quantities, wrapping around at the bounds of the scalar type.
+            #[inline]
+            pub fn wrapping_add(self, rhs: Self) -> Self
+            where
+                S: $crate::__num_traits::WrappingAdd,
+            {
+                $name($crate::__num_traits::WrappingAdd::wrapping_add(&self.0, &rhs.0), core::marker::PhantomData)
+            }
+            /// Subtracts two quantities, wrapping around at the bounds of the scalar type.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: Self) -> Self
+            where
+                S: $crate::__num_traits::WrappingSub,
+            {
+                $name($crate::__num_traits::WrappingSub::wrapping_sub(&self.0, &rhs.0), core::marker::PhantomData)
+            }
+        }
+
+        impl<S: $crate::Scalar, U> core::iter::Sum<$name<S, U>> for $name<S, U> where $name<S, U>: Default {
+            fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+                iter.fold(
+                    $name::default(),
+                    |a, b| a + b,
+                )
+            }
+        }
+    };
+    ($name:ident, $difference:ident) => {
+        impl<S: $crate::Scalar> core::ops::Add<$difference<S>> for $name<S> {
+            type Output = $name<S>;
+            fn add(self, rhs: $difference<S>) -> $name<S> {
+                $name(self.0 + $crate::Dimension::canonical(&rhs), core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::AddAssign<$difference<S>> for $name<S> {
+            fn add_assign(&mut self, rhs: $difference<S>) {
+                self.0 = self.0.clone() + $crate::Dimension::canonical(&rhs);
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::Sub<$difference<S>> for $name<S> {
+            type Output = $name<S>;
+            fn sub(self, rhs: $difference<S>) -> $name<S> {
+                $name(self.0 - $crate::Dimension::canonical(&rhs), core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::SubAssign<$difference<S>> for $name<S> {
+            fn sub_assign(&mut self, rhs: $difference<S>) {
+                self.0 = self.0.clone() - $crate::Dimension::canonical(&rhs);
+            }
+        }
+        impl<S: $crate::Scalar> $name<S> {
+            /// Offsets self by a difference, returning `None` on overflow.
+            #[inline]
+            pub fn checked_add(self, rhs: $difference<S>) -> Option<Self>
+            where
+                S: $crate::__num_traits::CheckedAdd,
+            {
+                $crate::__num_traits::CheckedAdd::checked_add(&self.0, &$crate::Dimension::canonical(&rhs)).map(|value| $name(value, core::marker::PhantomData))
+            }
+            /// Offsets self by the negation of a difference, returning `None` on overflow.
+            #[inline]
+            pub fn checked_sub(self, rhs: $difference<S>) -> Option<Self>
+            where
+                S: $crate::__num_traits::CheckedSub,
+            {
+                $crate::__num_traits::CheckedSub::checked_sub(&self.0, &$crate::Dimension::canonical(&rhs)).map(|value| $name(value, core::marker::PhantomData))
+            }
+            /// Offsets self by a difference, saturating at the bounds of the scalar type.
+            #[inline]
+            pub fn saturating_add(self, rhs: $difference<S>) -> Self
+            where
+                S: $crate::__num_traits::SaturatingAdd,
+            {
+                $name($crate::__num_traits::SaturatingAdd::saturating_add(&self.0, &$crate::Dimension::canonical(&rhs)), core::marker::PhantomData)
+            }
+            /// Offsets self by the negation of a difference, saturating at the bounds of the scalar type.
+            #[inline]
+            pub fn saturating_sub(self, rhs: $difference<S>) -> Self
+            where
+                S: $crate::__num_traits::SaturatingSub,
+            {
+                $name($crate::__num_traits::SaturatingSub::saturating_sub(&self.0, &$crate::Dimension::canonical(&rhs)), core::marker::PhantomData)
+            }
+            /// Offsets self by a difference, wrapping around at the bounds of the scalar type.
+            #[inline]
+            pub fn wrapping_add(self, rhs: $difference<S>) -> Self
+            where
+                S: $crate::__num_traits::WrappingAdd,
+            {
+                $name($crate::__num_traits::WrappingAdd::wrapping_add(&self.0, &$crate::Dimension::canonical(&rhs)), core::marker::PhantomData)
+            }
+            /// Offsets self by the negation of a difference, wrapping around at the bounds of the scalar type.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: $difference<S>) -> Self
+            where
+                S: $crate::__num_traits::WrappingSub,
+            {
+                $name($crate::__num_traits::WrappingSub::wrapping_sub(&self.0, &$crate::Dimension::canonical(&rhs)), core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar> core::ops::Sub<$name<S>> for $name<S> {
+            type Output = $difference<S>;
+            fn sub(self, rhs: $name<S>) -> $difference<S> {
+                $crate::Dimension::from_canonical(self.0 - rhs.0)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_mult_imp {
+    ($unit:ident, $dimension:ident, $($rhs:ident),*) => {
+        $(
             impl core::ops::Mul<$unit> for $rhs {
                 type Output = $dimension<$rhs>;
                 fn mul(self, _rhs: $unit) -> $dimension<$rhs> {
@@ -452,17 +1226,17 @@ macro_rules! __unit_mult_imp {
 #[doc(hidden)]
 #[cfg(not(feature = "const_operators"))]
 macro_rules! __dim_const_imp {
-    ($name:ident) => {};
+    ($name:ident $(, $difference:ident)?) => {};
 }
 #[macro_export]
 #[doc(hidden)]
 #[cfg(feature = "const_operators")]
 macro_rules! __dim_const_imp {
-    ($name:ident) => {
+    ($name:ident $(, $difference:ident)?) => {
         impl<S: $crate::Scalar> $name<S> {
             #[inline]
             pub const fn from_canonical(value: S) -> Self {
-                Self(value)
+                Self(value, core::marker::PhantomData)
             }
             #[inline]
             pub const fn canonical(&self) -> S
@@ -475,6 +1249,11 @@ macro_rules! __dim_const_imp {
         $crate::__dim_const_op_imp!(
             $name, f64, f32, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
         );
+        $crate::__dim_const_additive_imp!(
+            [f64, f32, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize]
+            $name $(, $difference)?
+        );
+        $crate::__dim_const_fixed_imp!($name $(, $difference)?);
     };
 }
 #[macro_export]
@@ -493,10 +1272,70 @@ macro_rules! __dim_const_op_imp {
                     self.canonical() * U::ONE_CANONICAL
                 }
 
+                /// Converts the dimension to the given affine unit.
+                #[inline]
+                pub const fn to_affine<U: $crate::Affine<$scalar, Self>>(&self) -> $scalar {
+                    (self.0 - U::ZERO.0) * U::ONE_CANONICAL
+                }
+                /// Creates a new quantity from a value in the given affine unit.
+                #[inline]
+                pub const fn from_affine<U: $crate::Affine<$scalar, Self>>(value: $scalar) -> Self {
+                    Self(value * U::ONE_DIFFERENCE + U::ZERO.0, core::marker::PhantomData)
+                }
+
+                /// Multiplies this quantity by a scalar value.
+                #[inline]
+                pub const fn mul_scalar(self, rhs: $scalar) -> Self {
+                    Self(self.0 * rhs, core::marker::PhantomData)
+                }
+                /// Multiplies this quantity by a scalar value.
+                #[inline]
+                pub const fn mul_assign_scalar(&mut self, rhs: $scalar) {
+                    self.0 *= rhs
+                }
+
+                /// Multiplies this quantity by a dimensionless ratio.
+                #[inline]
+                pub const fn mul_ratio(self, rhs: $crate::Ratio<$scalar>) -> Self {
+                    Self(self.0 * rhs.canonical(), core::marker::PhantomData)
+                }
+
+                /// Divides this quantity by another quantity of the same dimension.
+                #[inline]
+                pub const fn div(self, rhs: Self) -> $crate::Ratio<$scalar> {
+                    $crate::Ratio::from_canonical(self.0 / rhs.0)
+                }
+                /// Divides this quantity by a dimensionless ratio.
+                #[inline]
+                pub const fn div_ratio(self, rhs: $crate::Ratio<$scalar>) -> Self {
+                    Self(self.0 / rhs.canonical(), core::marker::PhantomData)
+                }
+                /// Divides this quantity by a scalar value.
+                #[inline]
+                pub const fn div_scalar(self, rhs: $scalar) -> Self {
+                    Self(self.0 / rhs, core::marker::PhantomData)
+                }
+                /// Divides this quantity by a scalar value.
+                #[inline]
+                pub const fn div_assign_scalar(&mut self, rhs: $scalar) {
+                    self.0 /= rhs
+                }
+            }
+        )*
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "const_operators")]
+macro_rules! __dim_const_additive_imp {
+    ([$($scalar:ident),*] $dimension:ident) => {
+        $(
+            impl $dimension<$scalar> {
                 /// Adds two quantities of the same dimension together.
                 #[inline]
                 pub const fn add(self, rhs: Self) -> Self {
-                    Self(self.0 + rhs.0)
+                    Self(self.0 + rhs.0, core::marker::PhantomData)
                 }
                 /// Adds two quantities of the same dimension together.
                 #[inline]
@@ -507,105 +1346,886 @@ macro_rules! __dim_const_op_imp {
                 /// Finds the difference between self and rhs.
                 #[inline]
                 pub const fn sub(self, rhs: Self) -> Self {
-                    Self(self.0 - rhs.0)
+                    Self(self.0 - rhs.0, core::marker::PhantomData)
                 }
                 /// Finds the difference between self and rhs.
                 #[inline]
                 pub const fn sub_assign(&mut self, rhs: Self) {
                     self.0 -= rhs.0
                 }
+            }
+        )*
+    };
+    ([$($scalar:ident),*] $dimension:ident, $difference:ident) => {
+        $crate::__paste! {
+            $(
+                impl $dimension<$scalar> {
+                    /// Offsets self by a difference.
+                    #[inline]
+                    pub const fn [<add_ $difference:lower>](self, rhs: $difference<$scalar>) -> Self {
+                        Self(self.0 + rhs.canonical(), core::marker::PhantomData)
+                    }
+                    /// Offsets self by a difference.
+                    #[inline]
+                    pub const fn [<add_assign_ $difference:lower>](&mut self, rhs: $difference<$scalar>) {
+                        self.0 += rhs.canonical()
+                    }
+
+                    /// Offsets self by the negation of a difference.
+                    #[inline]
+                    pub const fn [<sub_ $difference:lower>](self, rhs: $difference<$scalar>) -> Self {
+                        Self(self.0 - rhs.canonical(), core::marker::PhantomData)
+                    }
+                    /// Offsets self by the negation of a difference.
+                    #[inline]
+                    pub const fn [<sub_assign_ $difference:lower>](&mut self, rhs: $difference<$scalar>) {
+                        self.0 -= rhs.canonical()
+                    }
+
+                    /// Finds the difference between self and rhs.
+                    #[inline]
+                    pub const fn sub(self, rhs: Self) -> $difference<$scalar> {
+                        $difference::<$scalar>::from_canonical(self.0 - rhs.0)
+                    }
+                }
+            )*
+        }
+    };
+}
 
-                /// Multiplies this quantity by a scalar value.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "fixed")]
+macro_rules! __fixed_each {
+    ($callback:ident!($($args:tt)*)) => {
+        $crate::$callback!(@fixed FixedI8, LeEqU8, i8, $($args)*);
+        $crate::$callback!(@fixed FixedI16, LeEqU16, i16, $($args)*);
+        $crate::$callback!(@fixed FixedI32, LeEqU32, i32, $($args)*);
+        $crate::$callback!(@fixed FixedI64, LeEqU64, i64, $($args)*);
+        $crate::$callback!(@fixed FixedI128, LeEqU128, i128, $($args)*);
+        $crate::$callback!(@fixed FixedU8, LeEqU8, u8, $($args)*);
+        $crate::$callback!(@fixed FixedU16, LeEqU16, u16, $($args)*);
+        $crate::$callback!(@fixed FixedU32, LeEqU32, u32, $($args)*);
+        $crate::$callback!(@fixed FixedU64, LeEqU64, u64, $($args)*);
+        $crate::$callback!(@fixed FixedU128, LeEqU128, u128, $($args)*);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "const_operators", feature = "fixed")))]
+macro_rules! __dim_const_fixed_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "const_operators", feature = "fixed"))]
+macro_rules! __dim_const_fixed_imp {
+    ($dimension:ident $(, $difference:ident)?) => {
+        $crate::__fixed_each!(__dim_const_fixed_imp!($dimension $(, $difference)?));
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $dimension:ident $(, $difference:ident)?) => {
+        impl<Frac: $crate::__fixed::types::extra::$frac> $dimension<$crate::__fixed::$fixed<Frac>> {
+            /// Converts the dimension to the given unit.
+            #[inline]
+            pub const fn to<U: $crate::One<$crate::__fixed::$fixed<Frac>, Self>>(&self) -> $crate::__fixed::$fixed<Frac> {
+                self.0.unwrapped_mul(U::ONE_CANONICAL)
+            }
+
+            /// Converts the dimension to the given affine unit.
+            #[inline]
+            pub const fn to_affine<U: $crate::Affine<$crate::__fixed::$fixed<Frac>, Self>>(&self) -> $crate::__fixed::$fixed<Frac> {
+                self.0.unwrapped_sub(U::ZERO.0).unwrapped_mul(U::ONE_CANONICAL)
+            }
+            /// Creates a new quantity from a value in the given affine unit.
+            #[inline]
+            pub const fn from_affine<U: $crate::Affine<$crate::__fixed::$fixed<Frac>, Self>>(value: $crate::__fixed::$fixed<Frac>) -> Self {
+                Self(value.unwrapped_mul(U::ONE_DIFFERENCE).unwrapped_add(U::ZERO.0), core::marker::PhantomData)
+            }
+
+            /// Multiplies this quantity by a scalar value.
+            #[inline]
+            pub const fn mul_scalar(self, rhs: $crate::__fixed::$fixed<Frac>) -> Self {
+                Self(self.0.unwrapped_mul(rhs), core::marker::PhantomData)
+            }
+            /// Multiplies this quantity by a scalar value.
+            #[inline]
+            pub const fn mul_assign_scalar(&mut self, rhs: $crate::__fixed::$fixed<Frac>) {
+                self.0 = self.0.unwrapped_mul(rhs)
+            }
+
+            /// Multiplies this quantity by a dimensionless ratio.
+            #[inline]
+            pub const fn mul_ratio(self, rhs: $crate::Ratio<$crate::__fixed::$fixed<Frac>>) -> Self {
+                Self(self.0.unwrapped_mul(rhs.canonical()), core::marker::PhantomData)
+            }
+
+            /// Divides this quantity by another quantity of the same dimension.
+            #[inline]
+            pub const fn div(self, rhs: Self) -> $crate::Ratio<$crate::__fixed::$fixed<Frac>> {
+                $crate::Ratio::from_canonical(self.0.unwrapped_div(rhs.0))
+            }
+            /// Divides this quantity by a dimensionless ratio.
+            #[inline]
+            pub const fn div_ratio(self, rhs: $crate::Ratio<$crate::__fixed::$fixed<Frac>>) -> Self {
+                Self(self.0.unwrapped_div(rhs.canonical()), core::marker::PhantomData)
+            }
+            /// Divides this quantity by a scalar value.
+            #[inline]
+            pub const fn div_scalar(self, rhs: $crate::__fixed::$fixed<Frac>) -> Self {
+                Self(self.0.unwrapped_div(rhs), core::marker::PhantomData)
+            }
+            /// Divides this quantity by a scalar value.
+            #[inline]
+            pub const fn div_assign_scalar(&mut self, rhs: $crate::__fixed::$fixed<Frac>) {
+                self.0 = self.0.unwrapped_div(rhs)
+            }
+        }
+        $crate::__dim_const_fixed_imp!(@additive $fixed, $frac, $dimension $(, $difference)?);
+    };
+    (@additive $fixed:ident, $frac:ident, $dimension:ident) => {
+        impl<Frac: $crate::__fixed::types::extra::$frac> $dimension<$crate::__fixed::$fixed<Frac>> {
+            /// Adds two quantities of the same dimension together.
+            #[inline]
+            pub const fn add(self, rhs: Self) -> Self {
+                Self(self.0.unwrapped_add(rhs.0), core::marker::PhantomData)
+            }
+            /// Adds two quantities of the same dimension together.
+            #[inline]
+            pub const fn add_assign(&mut self, rhs: Self) {
+                self.0 = self.0.unwrapped_add(rhs.0)
+            }
+
+            /// Finds the difference between self and rhs.
+            #[inline]
+            pub const fn sub(self, rhs: Self) -> Self {
+                Self(self.0.unwrapped_sub(rhs.0), core::marker::PhantomData)
+            }
+            /// Finds the difference between self and rhs.
+            #[inline]
+            pub const fn sub_assign(&mut self, rhs: Self) {
+                self.0 = self.0.unwrapped_sub(rhs.0)
+            }
+        }
+    };
+    (@additive $fixed:ident, $frac:ident, $dimension:ident, $difference:ident) => {
+        $crate::__paste! {
+            impl<Frac: $crate::__fixed::types::extra::$frac> $dimension<$crate::__fixed::$fixed<Frac>> {
+                /// Offsets self by a difference.
                 #[inline]
-                pub const fn mul_scalar(self, rhs: $scalar) -> Self {
-                    Self(self.0 * rhs)
+                pub const fn [<add_ $difference:lower>](self, rhs: $difference<$crate::__fixed::$fixed<Frac>>) -> Self {
+                    Self(self.0.unwrapped_add(rhs.canonical()), core::marker::PhantomData)
                 }
-                /// Multiplies this quantity by a scalar value.
+                /// Offsets self by a difference.
                 #[inline]
-                pub const fn mul_assign_scalar(&mut self, rhs: $scalar) {
-                    self.0 *= rhs
+                pub const fn [<add_assign_ $difference:lower>](&mut self, rhs: $difference<$crate::__fixed::$fixed<Frac>>) {
+                    self.0 = self.0.unwrapped_add(rhs.canonical())
                 }
 
-
+                /// Offsets self by the negation of a difference.
                 #[inline]
-                pub const fn div(self, rhs: Self) -> Self {
-                    Self(self.0 / rhs.0)
+                pub const fn [<sub_ $difference:lower>](self, rhs: $difference<$crate::__fixed::$fixed<Frac>>) -> Self {
+                    Self(self.0.unwrapped_sub(rhs.canonical()), core::marker::PhantomData)
                 }
+                /// Offsets self by the negation of a difference.
                 #[inline]
-                pub const fn div_assign(&mut self, rhs: Self) {
-                    self.0 /= rhs.0
+                pub const fn [<sub_assign_ $difference:lower>](&mut self, rhs: $difference<$crate::__fixed::$fixed<Frac>>) {
+                    self.0 = self.0.unwrapped_sub(rhs.canonical())
                 }
-                /// Divides this quantity by a scalar value.
+
+                /// Finds the difference between self and rhs.
                 #[inline]
-                pub const fn div_scalar(self, rhs: $scalar) -> Self {
-                    Self(self.0 / rhs)
+                pub const fn sub(self, rhs: Self) -> $difference<$crate::__fixed::$fixed<Frac>> {
+                    $difference::from_canonical(self.0.unwrapped_sub(rhs.0))
                 }
-                /// Divides this quantity by a scalar value.
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "const_operators", feature = "fixed")))]
+macro_rules! __const_conversion_op_fixed_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "const_operators", feature = "fixed"))]
+macro_rules! __const_conversion_op_fixed_imp {
+    ($self:ident, Self $op:tt $rhs:ident => $output:ident in $output_unit:ty) => {
+        $crate::__fixed_each!(__const_conversion_op_fixed_imp!($self, Self $op $rhs => $output in $output_unit));
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $self:ident, Self * $rhs:ident => $output:ident in $output_unit:ty) => {
+        $crate::__paste! {
+            impl<Frac: $crate::__fixed::types::extra::$frac> $self<$crate::__fixed::$fixed<Frac>> {
                 #[inline]
-                pub const fn div_assign_scalar(&mut self, rhs: $scalar) {
-                    self.0 /= rhs
+                pub const fn [<mul_ $rhs:lower>](self, rhs: $rhs<$crate::__fixed::$fixed<Frac>>) -> $output<$crate::__fixed::$fixed<Frac>> {
+                    use $crate::One;
+                    $output::from_canonical(<$output_unit as One<$crate::__fixed::$fixed<Frac>, _>>::ONE.canonical().unwrapped_mul(self.mul_scalar(rhs.canonical()).canonical()))
                 }
             }
+        }
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $self:ident, Self / $rhs:ident => $output:ident in $output_unit:ty) => {
+        $crate::__paste! {
+            impl<Frac: $crate::__fixed::types::extra::$frac> $self<$crate::__fixed::$fixed<Frac>> {
+                #[inline]
+                pub const fn [<div_ $rhs:lower>](self, rhs: $rhs<$crate::__fixed::$fixed<Frac>>) -> $output<$crate::__fixed::$fixed<Frac>> {
+                    use $crate::One;
+                    $output::from_canonical(<$output_unit as One<$crate::__fixed::$fixed<Frac>, _>>::ONE.canonical().unwrapped_mul(self.div_scalar(rhs.canonical()).canonical()))
+                }
+            }
+        }
+    };
+}
+
+/// A macro for creating a new unit type.
+///
+/// This macro creates a new unit type and implements multiplication with scalars on it.
+///
+/// # Note
+///
+/// you will have to implement the `UnitOf` trait for the new unit type for this to compile.
+///
+/// # Examples
+///
+/// ```
+/// use shrewnit::{Length, UnitOf};
+///
+/// shrewnit::unit_type!(
+///     /// A unit of length.
+///     // The symbol, singular name and plural name are optional.
+///     pub Feet ["ft", "foot", "feet"] of dimension Length
+/// );
+///
+/// impl<S: shrewnit::Scalar> UnitOf<S, Length<S>> for Feet {
+///     fn from_canonical(canonical: S) -> S {
+///         canonical / S::from_f64(3.28084).unwrap()
+///     }
+///     fn to_canonical(converted: S) -> S {
+///         converted * S::from_f64(3.28084).unwrap()
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! unit_type {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $unit:ident $([$symbol:literal, $singular:literal, $plural:literal $(, $alias:literal)* $(,)?])? of dimension $dimension:ident
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+        $vis struct $unit;
+
+        $(
+            $crate::__unit_info_imp!($unit [$symbol, $singular, $plural $(, $alias)*]);
+        )?
+
+        impl<S: $crate::Scalar> core::ops::Mul<S> for $unit {
+            type Output = $dimension<S>;
+            fn mul(self, rhs: S) -> $dimension<S> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(rhs)
+            }
+        }
+
+        impl $unit {
+            #[inline]
+            pub fn from_scalar<S: $crate::Scalar>(value: S) -> $dimension<S> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<Self>(value)
+            }
+        }
+
+        $crate::__unit_mult_fixed_imp!($unit, $dimension);
+        $crate::__unit_mult_decimal_imp!($unit, $dimension);
+        $crate::__unit_mult_half_imp!($unit, $dimension);
+        $crate::__unit_mult_complex_imp!($unit, $dimension);
+        $crate::__unit_mult_measured_imp!($unit, $dimension);
+        $crate::__unit_mult_interval_imp!($unit, $dimension);
+        $crate::__unit_mult_imp!(
+            $unit,
+            $dimension,
+            f64,
+            f32,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            isize,
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            usize
+        );
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "fixed"))]
+macro_rules! __unit_mult_fixed_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "fixed")]
+macro_rules! __unit_mult_fixed_imp {
+    ($unit:ident, $dimension:ident) => {
+        $crate::__fixed_each!(__unit_mult_fixed_imp!($unit, $dimension));
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $unit:ident, $dimension:ident) => {
+        impl<Frac: $crate::__fixed::types::extra::$frac> core::ops::Mul<$unit> for $crate::__fixed::$fixed<Frac> {
+            type Output = $dimension<Self>;
+            fn mul(self, _rhs: $unit) -> $dimension<Self> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(self)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "decimal"))]
+macro_rules! __unit_mult_decimal_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "decimal")]
+macro_rules! __unit_mult_decimal_imp {
+    ($unit:ident, $dimension:ident) => {
+        impl core::ops::Mul<$unit> for $crate::__rust_decimal::Decimal {
+            type Output = $dimension<Self>;
+            fn mul(self, _rhs: $unit) -> $dimension<Self> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(self)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "half"))]
+macro_rules! __unit_mult_half_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "half")]
+macro_rules! __unit_mult_half_imp {
+    ($unit:ident, $dimension:ident) => {
+        $crate::__unit_mult_half_imp!(@half f16, $unit, $dimension);
+        $crate::__unit_mult_half_imp!(@half bf16, $unit, $dimension);
+    };
+    (@half $half:ident, $unit:ident, $dimension:ident) => {
+        impl core::ops::Mul<$unit> for $crate::__half::$half {
+            type Output = $dimension<Self>;
+            fn mul(self, _rhs: $unit) -> $dimension<Self> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(self)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "complex"))]
+macro_rules! __unit_mult_complex_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "complex")]
+macro_rules! __unit_mult_complex_imp {
+    ($unit:ident, $dimension:ident) => {
+        $crate::__unit_mult_complex_imp!(@complex f64, $unit, $dimension);
+        $crate::__unit_mult_complex_imp!(@complex f32, $unit, $dimension);
+    };
+    (@complex $float:ident, $unit:ident, $dimension:ident) => {
+        impl core::ops::Mul<$unit> for $crate::__num_complex::Complex<$float> {
+            type Output = $dimension<Self>;
+            fn mul(self, _rhs: $unit) -> $dimension<Self> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(self)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "complex", any(feature = "std", feature = "libm"))))]
+macro_rules! __dim_complex_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "complex", any(feature = "std", feature = "libm")))]
+macro_rules! __dim_complex_imp {
+    ($name:ident) => {
+        impl<T: $crate::Scalar + $crate::__num_traits::Float> $name<$crate::__num_complex::Complex<T>> {
+            /// Creates a phasor from its magnitude and phase.
+            #[inline]
+            pub fn from_polar(magnitude: $name<T>, phase: $crate::Angle<T>) -> Self {
+                $name(
+                    $crate::__num_complex::Complex::from_polar(
+                        $crate::Dimension::canonical(&magnitude),
+                        $crate::Dimension::canonical(&phase),
+                    ),
+                    core::marker::PhantomData,
+                )
+            }
+            /// Returns the magnitude of this phasor.
+            #[inline]
+            pub fn magnitude(&self) -> $name<T> {
+                $name(self.0.norm(), core::marker::PhantomData)
+            }
+            /// Returns the phase of this phasor.
+            #[inline]
+            pub fn phase(&self) -> $crate::Angle<T> {
+                $crate::Dimension::from_canonical(self.0.arg())
+            }
+            /// Returns the complex conjugate of this phasor, which has the opposite phase.
+            #[inline]
+            pub fn conj(&self) -> Self {
+                $name(self.0.conj(), core::marker::PhantomData)
+            }
+            /// Returns the real part of this phasor.
+            #[inline]
+            pub fn re(&self) -> $name<T> {
+                $name(self.0.re, core::marker::PhantomData)
+            }
+            /// Returns the imaginary part of this phasor.
+            #[inline]
+            pub fn im(&self) -> $name<T> {
+                $name(self.0.im, core::marker::PhantomData)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(any(feature = "std", feature = "libm")))]
+macro_rules! __unit_mult_measured_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! __unit_mult_measured_imp {
+    ($unit:ident, $dimension:ident) => {
+        impl<T: $crate::__num_traits::Float> core::ops::Mul<$unit> for $crate::measured::Measured<T> {
+            type Output = $dimension<Self>;
+            fn mul(self, _rhs: $unit) -> $dimension<Self> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(self)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_mult_interval_imp {
+    ($unit:ident, $dimension:ident) => {
+        impl<T: $crate::__num_traits::float::FloatCore> core::ops::Mul<$unit> for $crate::interval::Interval<T> {
+            type Output = $dimension<Self>;
+            fn mul(self, _rhs: $unit) -> $dimension<Self> {
+                use $crate::Dimension;
+                $dimension::from_scalar::<$unit>(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "const_operators"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_one_imp {
+    ($($tt:tt)*) => {};
+}
+#[cfg(feature = "const_operators")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_one_imp {
+    ($unit:ident, $dimension:ident, $rhsper:literal per canonical, $($scalar:ident),*) => {
+        $crate::__unit_one_fixed_imp!($unit, $dimension, $rhsper per canonical);
+        $crate::__unit_one_half_imp!($unit, $dimension, $rhsper per canonical);
+        $(
+            #[allow(clippy::excessive_precision)]
+            impl $crate::One<$scalar, $dimension<$scalar>> for $unit {
+                const ONE: $dimension<$scalar> = $dimension::from_canonical((1.0 / $rhsper) as $scalar);
+                const ONE_CANONICAL: $scalar = $rhsper as $scalar;
+            }
         )*
     };
-}
-
-/// A macro for creating a new unit type.
-///
-/// This macro creates a new unit type and implements multiplication with scalars on it.
-///
-/// # Note
-///
-/// you will have to implement the `UnitOf` trait for the new unit type for this to compile.
-///
-/// # Examples
-///
-/// ```
-/// use shrewnit::{Length, UnitOf};
-///
-/// shrewnit::unit_type!(
-///     /// A unit of length.
-///     pub Feet of dimension Length
-/// );
-///
-/// impl<S: shrewnit::Scalar> UnitOf<S, Length<S>> for Feet {
-///     fn from_canonical(canonical: S) -> S {
-///         canonical / S::from_f64(3.28084).unwrap()
-///     }
-///     fn to_canonical(converted: S) -> S {
-///         converted * S::from_f64(3.28084).unwrap()
-///     }
-/// }
-/// ```
-#[macro_export]
-macro_rules! unit_type {
+    ($unit:ident, $dimension:ident, per $lhsper:literal canonical, $($scalar:ident),*) => {
+        $crate::__unit_one_fixed_imp!($unit, $dimension, per $lhsper canonical);
+        $crate::__unit_one_half_imp!($unit, $dimension, per $lhsper canonical);
+        $(
+            #[allow(clippy::excessive_precision)]
+            impl $crate::One<$scalar, $dimension<$scalar>> for $unit {
+                const ONE: $dimension<$scalar> = $dimension::from_canonical($lhsper as $scalar);
+                const ONE_CANONICAL: $scalar = (1.0 / $lhsper) as $scalar;
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "const_operators"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_affine_imp {
+    ($($tt:tt)*) => {};
+}
+#[cfg(feature = "const_operators")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_affine_imp {
+    ($unit:ident, $dimension:ident, $rhsper:literal per canonical offset $offset:expr, $($scalar:ident),*) => {
+        $crate::__unit_affine_fixed_imp!($unit, $dimension, $rhsper per canonical offset $offset);
+        $crate::__unit_affine_half_imp!($unit, $dimension, $rhsper per canonical offset $offset);
+        $(
+            #[allow(clippy::excessive_precision)]
+            impl $crate::Affine<$scalar, $dimension<$scalar>> for $unit {
+                const ZERO: $dimension<$scalar> = $dimension::from_canonical(($offset) as $scalar);
+                const ONE_DIFFERENCE: $scalar = (1.0 / $rhsper) as $scalar;
+                const ONE_CANONICAL: $scalar = $rhsper as $scalar;
+            }
+        )*
+    };
+    ($unit:ident, $dimension:ident, per $lhsper:literal canonical offset $offset:expr, $($scalar:ident),*) => {
+        $crate::__unit_affine_fixed_imp!($unit, $dimension, per $lhsper canonical offset $offset);
+        $crate::__unit_affine_half_imp!($unit, $dimension, per $lhsper canonical offset $offset);
+        $(
+            #[allow(clippy::excessive_precision)]
+            impl $crate::Affine<$scalar, $dimension<$scalar>> for $unit {
+                const ZERO: $dimension<$scalar> = $dimension::from_canonical(($offset) as $scalar);
+                const ONE_DIFFERENCE: $scalar = $lhsper as $scalar;
+                const ONE_CANONICAL: $scalar = (1.0 / $lhsper) as $scalar;
+            }
+        )*
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "const_operators", feature = "fixed")))]
+macro_rules! __unit_one_fixed_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "const_operators", feature = "fixed"))]
+macro_rules! __unit_one_fixed_imp {
+    ($unit:ident, $dimension:ident, $($factor:tt)*) => {
+        $crate::__fixed_each!(__unit_one_fixed_imp!($unit, $dimension, $($factor)*));
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $unit:ident, $dimension:ident, $rhsper:literal per canonical) => {
+        #[allow(clippy::excessive_precision)]
+        impl<Frac: $crate::__fixed::types::extra::$frac> $crate::One<$crate::__fixed::$fixed<Frac>, $dimension<$crate::__fixed::$fixed<Frac>>> for $unit {
+            const ONE: $dimension<$crate::__fixed::$fixed<Frac>> = $dimension::from_canonical($crate::__fixed_const!($fixed, $inner, 1.0 / $rhsper));
+            const ONE_CANONICAL: $crate::__fixed::$fixed<Frac> = $crate::__fixed_const!($fixed, $inner, $rhsper);
+        }
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $unit:ident, $dimension:ident, per $lhsper:literal canonical) => {
+        #[allow(clippy::excessive_precision)]
+        impl<Frac: $crate::__fixed::types::extra::$frac> $crate::One<$crate::__fixed::$fixed<Frac>, $dimension<$crate::__fixed::$fixed<Frac>>> for $unit {
+            const ONE: $dimension<$crate::__fixed::$fixed<Frac>> = $dimension::from_canonical($crate::__fixed_const!($fixed, $inner, $lhsper));
+            const ONE_CANONICAL: $crate::__fixed::$fixed<Frac> = $crate::__fixed_const!($fixed, $inner, 1.0 / $lhsper);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "const_operators", feature = "fixed")))]
+macro_rules! __unit_affine_fixed_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "const_operators", feature = "fixed"))]
+macro_rules! __unit_affine_fixed_imp {
+    ($unit:ident, $dimension:ident, $($factor:tt)*) => {
+        $crate::__fixed_each!(__unit_affine_fixed_imp!($unit, $dimension, $($factor)*));
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $unit:ident, $dimension:ident, $rhsper:literal per canonical offset $offset:expr) => {
+        #[allow(clippy::excessive_precision)]
+        impl<Frac: $crate::__fixed::types::extra::$frac> $crate::Affine<$crate::__fixed::$fixed<Frac>, $dimension<$crate::__fixed::$fixed<Frac>>> for $unit {
+            const ZERO: $dimension<$crate::__fixed::$fixed<Frac>> = $dimension::from_canonical($crate::__fixed_const!($fixed, $inner, $offset));
+            const ONE_DIFFERENCE: $crate::__fixed::$fixed<Frac> = $crate::__fixed_const!($fixed, $inner, 1.0 / $rhsper);
+            const ONE_CANONICAL: $crate::__fixed::$fixed<Frac> = $crate::__fixed_const!($fixed, $inner, $rhsper);
+        }
+    };
+    (@fixed $fixed:ident, $frac:ident, $inner:ident, $unit:ident, $dimension:ident, per $lhsper:literal canonical offset $offset:expr) => {
+        #[allow(clippy::excessive_precision)]
+        impl<Frac: $crate::__fixed::types::extra::$frac> $crate::Affine<$crate::__fixed::$fixed<Frac>, $dimension<$crate::__fixed::$fixed<Frac>>> for $unit {
+            const ZERO: $dimension<$crate::__fixed::$fixed<Frac>> = $dimension::from_canonical($crate::__fixed_const!($fixed, $inner, $offset));
+            const ONE_DIFFERENCE: $crate::__fixed::$fixed<Frac> = $crate::__fixed_const!($fixed, $inner, $lhsper);
+            const ONE_CANONICAL: $crate::__fixed::$fixed<Frac> = $crate::__fixed_const!($fixed, $inner, 1.0 / $lhsper);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "const_operators", feature = "half")))]
+macro_rules! __unit_one_half_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "const_operators", feature = "half"))]
+macro_rules! __unit_one_half_imp {
+    ($unit:ident, $dimension:ident, $($factor:tt)*) => {
+        $crate::__unit_one_half_imp!(@half f16, $unit, $dimension, $($factor)*);
+        $crate::__unit_one_half_imp!(@half bf16, $unit, $dimension, $($factor)*);
+    };
+    (@half $half:ident, $unit:ident, $dimension:ident, $rhsper:literal per canonical) => {
+        #[allow(clippy::excessive_precision)]
+        impl $crate::One<$crate::__half::$half, $dimension<$crate::__half::$half>> for $unit {
+            const ONE: $dimension<$crate::__half::$half> = $dimension::from_canonical($crate::__half::$half::from_f64_const(1.0 / $rhsper));
+            const ONE_CANONICAL: $crate::__half::$half = $crate::__half::$half::from_f64_const($rhsper);
+        }
+    };
+    (@half $half:ident, $unit:ident, $dimension:ident, per $lhsper:literal canonical) => {
+        #[allow(clippy::excessive_precision)]
+        impl $crate::One<$crate::__half::$half, $dimension<$crate::__half::$half>> for $unit {
+            const ONE: $dimension<$crate::__half::$half> = $dimension::from_canonical($crate::__half::$half::from_f64_const($lhsper));
+            const ONE_CANONICAL: $crate::__half::$half = $crate::__half::$half::from_f64_const(1.0 / $lhsper);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(all(feature = "const_operators", feature = "half")))]
+macro_rules! __unit_affine_half_imp {
+    ($($tt:tt)*) => {};
+}
+#[macro_export]
+#[doc(hidden)]
+#[cfg(all(feature = "const_operators", feature = "half"))]
+macro_rules! __unit_affine_half_imp {
+    ($unit:ident, $dimension:ident, $($factor:tt)*) => {
+        $crate::__unit_affine_half_imp!(@half f16, $unit, $dimension, $($factor)*);
+        $crate::__unit_affine_half_imp!(@half bf16, $unit, $dimension, $($factor)*);
+    };
+    (@half $half:ident, $unit:ident, $dimension:ident, $rhsper:literal per canonical offset $offset:expr) => {
+        #[allow(clippy::excessive_precision)]
+        impl $crate::Affine<$crate::__half::$half, $dimension<$crate::__half::$half>> for $unit {
+            const ZERO: $dimension<$crate::__half::$half> = $dimension::from_canonical($crate::__half::$half::from_f64_const($offset));
+            const ONE_DIFFERENCE: $crate::__half::$half = $crate::__half::$half::from_f64_const(1.0 / $rhsper);
+            const ONE_CANONICAL: $crate::__half::$half = $crate::__half::$half::from_f64_const($rhsper);
+        }
+    };
+    (@half $half:ident, $unit:ident, $dimension:ident, per $lhsper:literal canonical offset $offset:expr) => {
+        #[allow(clippy::excessive_precision)]
+        impl $crate::Affine<$crate::__half::$half, $dimension<$crate::__half::$half>> for $unit {
+            const ZERO: $dimension<$crate::__half::$half> = $dimension::from_canonical($crate::__half::$half::from_f64_const($offset));
+            const ONE_DIFFERENCE: $crate::__half::$half = $crate::__half::$half::from_f64_const($lhsper);
+            const ONE_CANONICAL: $crate::__half::$half = $crate::__half::$half::from_f64_const(1.0 / $lhsper);
+        }
+    };
+}
+
+/// A macro for creating a new unit type with simple conversions. Used internally by [`dimension!`](dimension)
+///
+/// Conversions are implemented by multiplying or dividing by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use shrewnit::{Length};
+///
+/// shrewnit::simple_unit!(
+///     /// A unit of length.
+///     pub Feet of dimension Length = 3.28084 per canonical
+/// );
+/// ```
+///
+/// The symbol, singular name, plural name and any aliases of the unit can be given in brackets after its name.
+/// These are exposed through [`UnitInfo`](crate::units::UnitInfo), along with the conversion factor.
+///
+/// ```
+/// use shrewnit::{units::UnitInfo, Length};
+///
+/// shrewnit::simple_unit!(
+///     /// A unit of length.
+///     pub Furlongs ["fur", "furlong", "furlongs"] of dimension Length = per 201.168 canonical
+/// );
+///
+/// assert_eq!(Furlongs::SYMBOL, "fur");
+/// assert_eq!(Furlongs::FACTOR, Some(201.168));
+/// ```
+///
+/// Units can also be tagged with the [systems](crate::units::UnitSystem) they belong to, which `humanize` uses to pick a unit.
+///
+/// ```
+/// use shrewnit::{units::{UnitInfo, UnitSystem}, Length};
+///
+/// shrewnit::simple_unit!(
+///     /// A unit of length.
+///     pub Chains ["ch", "chain", "chains"] in imperial of dimension Length = per 20.1168 canonical
+/// );
+///
+/// assert_eq!(Chains::SYSTEMS, &[UnitSystem::Imperial]);
+/// ```
+///
+/// Units with affine conversions, whose zero isn't the zero of the canonical unit, are declared with an `offset`.
+/// The offset is the value of zero in this unit, in canonical units.
+/// These units implement [`Affine`] instead of [`One`].
+///
+/// ```
+/// use shrewnit::prelude::*;
+///
+/// shrewnit::simple_unit!(
+///     /// Gauge pressure in pounds per square inch, relative to one standard atmosphere.
+///     pub Psig of dimension Pressure = per 6894.757 canonical offset 101325.0
+/// );
+///
+/// let tire = 32.0f64 * Psig;
+/// assert_eq!(tire.to::<Pascals>(), 32.0 * 6894.757 + 101325.0);
+/// ```
+///
+/// When the offset isn't a round number of canonical units, the unit can instead be declared with `zero at`,
+/// the value of the canonical zero in this unit, which keeps conversions exact for decimal scalars.
+///
+/// ```
+/// use shrewnit::prelude::*;
+///
+/// shrewnit::simple_unit!(
+///     /// Degrees Rømer, where water freezes at 7.5 and boils at 60.
+///     pub Romer of dimension Temperature = 0.525 per canonical zero at -135.90375
+/// );
+///
+/// let boiling = 60.0f64 * Romer;
+/// assert!((Dimension::to::<Celsius>(&boiling) - 100.0).abs() < 1e-9);
+/// ```
+#[macro_export]
+macro_rules! simple_unit {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $unit:ident $([$symbol:literal, $singular:literal, $plural:literal $(, $alias:literal)* $(,)?])? $(in $($system:ident),+)? of dimension $dimension:ident = $($rhsper:literal per canonical)? $(per $lhsper:literal canonical)? offset $offset:literal
+    ) => {
+        $crate::unit_type!(
+            $(#[$meta])*
+            $vis $unit of dimension $dimension
+        );
+        $crate::__unit_info_imp!($unit $([$symbol, $singular, $plural $(, $alias)*])? $(in [$($system),+])? => $(1.0 / $rhsper)? $($lhsper)?, $offset);
+
+        $crate::__unit_affine_imp!(
+            $unit,
+            $dimension,
+            $($rhsper per canonical)? $(per $lhsper canonical)? offset $offset,
+            f64,
+            f32,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            isize,
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            usize
+        );
+        $(
+            impl<S: $crate::Scalar> $crate::UnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn from_canonical(canonical: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_from_canonical(canonical).unwrap()
+                }
+                #[inline]
+                fn to_canonical(converted: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_to_canonical(converted).unwrap()
+                }
+            }
+            impl<S: $crate::Scalar> $crate::TryUnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn try_from_canonical(canonical: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        canonical,
+                        $crate::__factor!($offset),
+                        $crate::__factor!($rhsper),
+                        $crate::units::__Factor::ONE,
+                        $crate::units::__Factor::ZERO,
+                    )
+                }
+                #[inline]
+                fn try_to_canonical(converted: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        converted,
+                        $crate::units::__Factor::ZERO,
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($rhsper),
+                        $crate::__factor!($offset),
+                    )
+                }
+            }
+        )?
+        $(
+            impl<S: $crate::Scalar> $crate::UnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn from_canonical(canonical: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_from_canonical(canonical).unwrap()
+                }
+                #[inline]
+                fn to_canonical(converted: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_to_canonical(converted).unwrap()
+                }
+            }
+            impl<S: $crate::Scalar> $crate::TryUnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn try_from_canonical(canonical: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        canonical,
+                        $crate::__factor!($offset),
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($lhsper),
+                        $crate::units::__Factor::ZERO,
+                    )
+                }
+                #[inline]
+                fn try_to_canonical(converted: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        converted,
+                        $crate::units::__Factor::ZERO,
+                        $crate::__factor!($lhsper),
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($offset),
+                    )
+                }
+            }
+        )?
+    };
     (
         $(#[$meta:meta])*
-        $vis:vis $unit:ident of dimension $dimension:ident
+        $vis:vis $unit:ident $([$symbol:literal, $singular:literal, $plural:literal $(, $alias:literal)* $(,)?])? $(in $($system:ident),+)? of dimension $dimension:ident = $($rhsper:literal per canonical)? $(per $lhsper:literal canonical)? zero at $zero:literal
     ) => {
-        $(#[$meta])*
-        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
-        $vis struct $unit;
-
-        impl<S: $crate::Scalar> core::ops::Mul<S> for $unit {
-            type Output = $dimension<S>;
-            fn mul(self, rhs: S) -> $dimension<S> {
-                use $crate::Dimension;
-                $dimension::from_scalar::<$unit>(rhs)
-            }
-        }
-
-        impl $unit {
-            #[inline]
-            pub fn from_scalar<S: $crate::Scalar>(value: S) -> $dimension<S> {
-                use $crate::Dimension;
-                $dimension::from_scalar::<Self>(value)
-            }
-        }
+        $crate::unit_type!(
+            $(#[$meta])*
+            $vis $unit of dimension $dimension
+        );
+        $crate::__unit_info_imp!($unit $([$symbol, $singular, $plural $(, $alias)*])? $(in [$($system),+])? => $(1.0 / $rhsper)? $($lhsper)?, $(-($zero) / $rhsper)? $(-($zero) * $lhsper)?);
 
-        $crate::__unit_mult_imp!(
+        $crate::__unit_affine_imp!(
             $unit,
             $dimension,
+            $($rhsper per canonical offset (-($zero) / $rhsper))? $(per $lhsper canonical offset (-($zero) * $lhsper))?,
             f64,
             f32,
             i8,
@@ -621,63 +2241,88 @@ macro_rules! unit_type {
             u128,
             usize
         );
-    };
-}
-
-#[cfg(not(feature = "const_operators"))]
-#[macro_export]
-#[doc(hidden)]
-macro_rules! __unit_one_imp {
-    ($($tt:tt)*) => {};
-}
-#[cfg(feature = "const_operators")]
-#[macro_export]
-#[doc(hidden)]
-macro_rules! __unit_one_imp {
-    ($unit:ident, $dimension:ident, $rhsper:literal per canonical, $($scalar:ident),*) => {
         $(
-            #[allow(clippy::excessive_precision)]
-            impl $crate::One<$scalar, $dimension<$scalar>> for $unit {
-                const ONE: $dimension<$scalar> = $dimension::from_canonical((1.0 / $rhsper) as $scalar);
-                const ONE_CANONICAL: $scalar = $rhsper as $scalar;
+            impl<S: $crate::Scalar> $crate::UnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn from_canonical(canonical: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_from_canonical(canonical).unwrap()
+                }
+                #[inline]
+                fn to_canonical(converted: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_to_canonical(converted).unwrap()
+                }
             }
-        )*
-    };
-    ($unit:ident, $dimension:ident, per $lhsper:literal canonical, $($scalar:ident),*) => {
+            impl<S: $crate::Scalar> $crate::TryUnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn try_from_canonical(canonical: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert_zero(
+                        canonical,
+                        $crate::__factor!($zero),
+                        $crate::__factor!(-($zero) / $rhsper),
+                        $crate::__factor!($rhsper),
+                        $crate::units::__Factor::ONE,
+                        false,
+                    )
+                }
+                #[inline]
+                fn try_to_canonical(converted: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert_zero(
+                        converted,
+                        $crate::__factor!($zero),
+                        $crate::__factor!(-($zero) / $rhsper),
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($rhsper),
+                        true,
+                    )
+                }
+            }
+        )?
         $(
-            #[allow(clippy::excessive_precision)]
-            impl $crate::One<$scalar, $dimension<$scalar>> for $unit {
-                const ONE: $dimension<$scalar> = $dimension::from_canonical($lhsper as $scalar);
-                const ONE_CANONICAL: $scalar = (1.0 / $lhsper) as $scalar;
+            impl<S: $crate::Scalar> $crate::UnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn from_canonical(canonical: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_from_canonical(canonical).unwrap()
+                }
+                #[inline]
+                fn to_canonical(converted: S) -> S {
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_to_canonical(converted).unwrap()
+                }
             }
-        )*
+            impl<S: $crate::Scalar> $crate::TryUnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn try_from_canonical(canonical: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert_zero(
+                        canonical,
+                        $crate::__factor!($zero),
+                        $crate::__factor!(-($zero) * $lhsper),
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($lhsper),
+                        false,
+                    )
+                }
+                #[inline]
+                fn try_to_canonical(converted: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert_zero(
+                        converted,
+                        $crate::__factor!($zero),
+                        $crate::__factor!(-($zero) * $lhsper),
+                        $crate::__factor!($lhsper),
+                        $crate::units::__Factor::ONE,
+                        true,
+                    )
+                }
+            }
+        )?
     };
-}
-
-/// A macro for creating a new unit type with simple conversions. Used internally by [`dimension!`](dimension)
-///
-/// Conversions are implemented by multiplying or dividing by a scalar value.
-///
-/// # Examples
-///
-/// ```
-/// use shrewnit::{Length};
-///
-/// shrewnit::simple_unit!(
-///     /// A unit of length.
-///     pub Feet of dimension Length = 3.28084 per canonical
-/// );
-/// ```
-#[macro_export]
-macro_rules! simple_unit {
     (
         $(#[$meta:meta])*
-        $vis:vis $unit:ident of dimension $dimension:ident = $($rhsper:literal per canonical)? $(per $lhsper:literal canonical)?
+        $vis:vis $unit:ident $([$symbol:literal, $singular:literal, $plural:literal $(, $alias:literal)* $(,)?])? $(in $($system:ident),+)? of dimension $dimension:ident = $($rhsper:literal per canonical)? $(per $lhsper:literal canonical)?
     ) => {
         $crate::unit_type!(
             $(#[$meta])*
             $vis $unit of dimension $dimension
         );
+        $crate::__unit_info_imp!($unit $([$symbol, $singular, $plural $(, $alias)*])? $(in [$($system),+])? => $(1.0 / $rhsper)? $($lhsper)?, 0.0);
 
         $(
             $crate::__unit_one_imp!(
@@ -702,11 +2347,33 @@ macro_rules! simple_unit {
             impl<S: $crate::Scalar> $crate::UnitOf<S, $dimension<S>> for $unit {
                 #[inline]
                 fn from_canonical(canonical: S) -> S {
-                    S::from_f64(canonical.as_() * $rhsper).unwrap()
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_from_canonical(canonical).unwrap()
                 }
                 #[inline]
                 fn to_canonical(converted: S) -> S {
-                    S::from_f64(converted.as_() / $rhsper).unwrap()
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_to_canonical(converted).unwrap()
+                }
+            }
+            impl<S: $crate::Scalar> $crate::TryUnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn try_from_canonical(canonical: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        canonical,
+                        $crate::units::__Factor::ZERO,
+                        $crate::__factor!($rhsper),
+                        $crate::units::__Factor::ONE,
+                        $crate::units::__Factor::ZERO,
+                    )
+                }
+                #[inline]
+                fn try_to_canonical(converted: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        converted,
+                        $crate::units::__Factor::ZERO,
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($rhsper),
+                        $crate::units::__Factor::ZERO,
+                    )
                 }
             }
         )?
@@ -733,11 +2400,33 @@ macro_rules! simple_unit {
             impl<S: $crate::Scalar> $crate::UnitOf<S, $dimension<S>> for $unit {
                 #[inline]
                 fn from_canonical(canonical: S) -> S {
-                    S::from_f64(canonical.as_() /  $lhsper).unwrap()
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_from_canonical(canonical).unwrap()
                 }
                 #[inline]
                 fn to_canonical(converted: S) -> S {
-                     S::from_f64(converted.as_() * $lhsper).unwrap()
+                    <Self as $crate::TryUnitOf<S, $dimension<S>>>::try_to_canonical(converted).unwrap()
+                }
+            }
+            impl<S: $crate::Scalar> $crate::TryUnitOf<S, $dimension<S>> for $unit {
+                #[inline]
+                fn try_from_canonical(canonical: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        canonical,
+                        $crate::units::__Factor::ZERO,
+                        $crate::units::__Factor::ONE,
+                        $crate::__factor!($lhsper),
+                        $crate::units::__Factor::ZERO,
+                    )
+                }
+                #[inline]
+                fn try_to_canonical(converted: S) -> Result<S, $crate::units::ConversionError> {
+                    $crate::units::__try_convert(
+                        converted,
+                        $crate::units::__Factor::ZERO,
+                        $crate::__factor!($lhsper),
+                        $crate::units::__Factor::ONE,
+                        $crate::units::__Factor::ZERO,
+                    )
                 }
             }
         )?
@@ -757,51 +2446,194 @@ macro_rules! simple_unit {
 ///     /// A dimension of some kind.
 ///     pub MyCustomDimension {
 ///         canonical: MyStandardUnit,
+///         // Optional exponents of the base dimensions (length, mass, time, current, temperature and angle).
+///         // Unlisted base dimensions have an exponent of zero.
+///         // The canonical unit must be coherent with the SI units of the base dimensions for the
+///         // conversions to and from `Quantity` to be meaningful.
+///         exponents: [length: 2],
 ///
-///         MyStandardUnit: 1.0 per canonical,
+///         // Optional symbol, singular name, plural name and any aliases, used when parsing.
+///         // Units without them use the name of their type instead.
+///         MyStandardUnit ["msu", "standard unit", "standard units", "stdu"]: 1.0 per canonical,
 ///
-///         MyHalfUnit: 2.0 per canonical,
+///         // Optional systems of units, used to pick a unit when humanizing a quantity.
+///         MyHalfUnit in metric, imperial: 2.0 per canonical,
 ///         MyDoubleUnit: per 2.0 canonical,
+///         // Affine units also give the value of their zero in canonical units,
+///         // or the value of the canonical zero in their own unit.
+///         MyOffsetUnit: 1.0 per canonical offset 10.0,
+///         MyZeroUnit: 1.8 per canonical zero at -32.0,
 ///     } where {
 ///         // Optional conversions block.
 ///         // Self </ or *> <other or same dimension type> => <output dimension type> in <output units>
 ///     }
 /// );
 /// ```
+///
+/// Relations declared with `<=>` instead of `=>` also implement the commuted product and both inverse quotients.
+/// Declare each of these relations only once, and use `Self * Self` for squares.
+/// Because this implements operators on the other dimensions of the relation,
+/// all three dimensions must be defined in the same crate.
+///
+/// ```
+/// shrewnit::dimension!(
+///     pub Distance {
+///         canonical: Leagues,
+///         Leagues: 1.0 per canonical,
+///     } where {
+///         // Also implements `Duration * Speed`, `Speed * Duration` and `Distance / Speed`.
+///         Self / Duration <=> Speed in LeaguesPerFortnight,
+///         // Also implements `Plot / Distance`.
+///         Self * Self <=> Plot in SquareLeagues,
+///     }
+/// );
+/// # shrewnit::dimension!(pub Duration { canonical: Fortnights, Fortnights: 1.0 per canonical, });
+/// # shrewnit::dimension!(pub Speed { canonical: LeaguesPerFortnight, LeaguesPerFortnight: 1.0 per canonical, });
+/// # shrewnit::dimension!(pub Plot { canonical: SquareLeagues, SquareLeagues: 1.0 per canonical, });
+///
+/// let speed = Leagues * 4.0 / (Fortnights * 2.0);
+/// let distance = speed * (Fortnights * 3.0);
+/// let plot: Plot = distance * distance;
+/// let side: Distance = plot / distance;
+/// ```
+///
+/// Dimensions of points on a scale with an arbitrary zero, like absolute temperature,
+/// can be declared `with differences in` another dimension.
+/// These can't be added together, but subtracting two of them gives the difference dimension,
+/// which can then be added to or subtracted from them.
+///
+/// ```
+/// shrewnit::dimension!(
+///     pub Elevation with differences in Height {
+///         canonical: MetersAboveSeaLevel,
+///         MetersAboveSeaLevel: 1.0 per canonical,
+///     }
+/// );
+/// # shrewnit::dimension!(pub Height { canonical: MetersHigh, MetersHigh: 1.0 per canonical, });
+///
+/// let climb: Height = MetersAboveSeaLevel * 2500.0 - MetersAboveSeaLevel * 1800.0;
+/// let summit: Elevation = MetersAboveSeaLevel * 1800.0 + climb;
+/// ```
+///
+/// These dimensions can't declare exponents, which would let them be converted into a [`Quantity`](crate::quantity::Quantity)
+/// of the same type as their differences and added there.
 #[macro_export]
 macro_rules! dimension {
     (
         $(#[$meta:meta])*
         $vis:vis $name:ident {
             canonical: $canonical_unit:ident,
+            exponents: [$($exponents:tt)*],
+            $($units:tt)*
+        } $($converts:tt)*
+    ) => {
+        $crate::dimension!(
+            @exponents [$($exponents)*]
+            $(#[$meta])*
+            $vis $name {
+                canonical: $canonical_unit,
+                $($units)*
+            } $($converts)*
+        );
+    };
+    (
+        $(@exponents [$($exponents:tt)*])?
+        $(#[$meta:meta])*
+        $vis:vis $name:ident $(with differences in $difference:ident)? {
+            canonical: $canonical_unit:ident,
 
             $(
                 $(#[$unit_meta:meta])*
-                $unit:ident: $($rhsper:literal per canonical)? $(per $lhsper:literal canonical)?,
+                $unit:ident $([$symbol:literal, $singular:literal, $plural:literal $(, $alias:literal)* $(,)?])? $(in $($system:ident),+)?: $($rhsper:literal per canonical)? $(per $lhsper:literal canonical)? $(offset $offset:literal)? $(zero at $zero:literal)?,
             )+
         } $(where {
             $($converts:tt)*
         })?
     ) => {
         $(#[$meta])*
-        #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Default)]
-        $vis struct $name<S: $crate::Scalar = f64>(S);
+        $vis struct $name<S: $crate::Scalar = f64, U = $canonical_unit>(S, core::marker::PhantomData<U>);
+
+        // These are implemented manually so they don't require anything of the storage unit.
+        #[allow(clippy::non_canonical_clone_impl)]
+        impl<S: $crate::Scalar, U> Clone for $name<S, U> {
+            #[inline]
+            fn clone(&self) -> Self {
+                $name(self.0.clone(), core::marker::PhantomData)
+            }
+        }
+        impl<S: $crate::Scalar + Copy, U> Copy for $name<S, U> {}
+        impl<S: $crate::Scalar + PartialEq, U> PartialEq for $name<S, U> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl<S: $crate::Scalar + Eq, U> Eq for $name<S, U> {}
+        impl<S: $crate::Scalar + PartialOrd, U> PartialOrd for $name<S, U> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+        impl<S: $crate::Scalar + Ord, U> Ord for $name<S, U> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        impl<S: $crate::Scalar + Default, U> Default for $name<S, U> {
+            #[inline]
+            fn default() -> Self {
+                $name(S::default(), core::marker::PhantomData)
+            }
+        }
 
         impl<S: $crate::Scalar> $crate::Dimension<S> for $name<S> {
             type CanonicalUnit = $canonical_unit;
 
+            const UNITS: &'static [$crate::units::UnitDescriptor] = &[
+                $($crate::__unit_descriptor!($unit)),+
+            ];
+
             #[inline]
             fn canonical(&self) -> S {
                 self.0.clone()
             }
             #[inline]
             fn from_canonical(value: S) -> Self {
-                Self(value)
+                Self(value, core::marker::PhantomData)
             }
         }
-        $crate::__dim_const_imp!($name);
+        $crate::__dim_const_imp!($name $(, $difference)?);
+        $crate::__dim_complex_imp!($name);
+
+        $(
+            impl<S: $crate::Scalar> $crate::exponents::Dimensioned for $name<S> {
+                type Exponents = $crate::exponents!($($exponents)*);
+            }
+            impl<S: $crate::Scalar> core::convert::From<$name<S>> for $crate::quantity::Quantity<S, $crate::exponents!($($exponents)*)> {
+                #[inline]
+                fn from(value: $name<S>) -> Self {
+                    Self::new(value.0)
+                }
+            }
+            impl<S: $crate::Scalar> core::convert::From<$crate::quantity::Quantity<S, $crate::exponents!($($exponents)*)>> for $name<S> {
+                #[inline]
+                fn from(value: $crate::quantity::Quantity<S, $crate::exponents!($($exponents)*)>) -> Self {
+                    Self(value.into_value(), core::marker::PhantomData)
+                }
+            }
+            impl<S: $crate::Scalar> core::convert::TryFrom<$crate::dynamic::DynQuantity<S>> for $name<S> {
+                type Error = $crate::dynamic::DimensionMismatch;
+                #[inline]
+                fn try_from(value: $crate::dynamic::DynQuantity<S>) -> Result<Self, Self::Error> {
+                    value.expect_exponents(<$crate::exponents!($($exponents)*) as $crate::exponents::Exponents>::EXPONENTS)?;
+                    Ok(Self(value.into_value(), core::marker::PhantomData))
+                }
+            }
+        )?
 
-        impl<S: $crate::Scalar + PartialOrd> $name<S> {
+        impl<S: $crate::Scalar + PartialOrd, U> $name<S, U> {
             pub fn max(self, other: Self) -> Self {
                 if self >= other {
                     self
@@ -818,75 +2650,201 @@ macro_rules! dimension {
             }
         }
 
-        impl<S: $crate::Scalar + core::fmt::Debug> core::fmt::Debug for $name<S> {
+        impl<S: $crate::Scalar + core::fmt::Debug, U: core::fmt::Debug + Default> core::fmt::Debug for $name<S, U> {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}({:?} {})", stringify!($name), self.0, stringify!($canonical_unit))
+                write!(f, "{}({:?} {:?})", stringify!($name), self.0, U::default())
             }
         }
 
-        impl<S: $crate::Scalar> core::ops::Mul<S> for $name<S> {
-            type Output = $name<S>;
-            fn mul(self, rhs: S) -> $name<S> {
-                $name(self.0 * rhs)
+        impl<S: $crate::Scalar, U> core::ops::Mul<S> for $name<S, U> {
+            type Output = $name<S, U>;
+            fn mul(self, rhs: S) -> $name<S, U> {
+                $name(self.0 * rhs, core::marker::PhantomData)
             }
         }
-        impl<S: $crate::Scalar> core::ops::MulAssign<S> for $name<S> {
+        impl<S: $crate::Scalar, U> core::ops::MulAssign<S> for $name<S, U> {
             fn mul_assign(&mut self, rhs: S) {
                 self.0 = self.0.clone() * rhs;
             }
         }
 
-        impl<S: $crate::Scalar> core::ops::Div<S> for $name<S> {
-            type Output = $name<S>;
-            fn div(self, rhs: S) -> $name<S> {
-                $name(self.0 / rhs)
+        impl<S: $crate::Scalar, U> core::ops::Div<S> for $name<S, U> {
+            type Output = $name<S, U>;
+            fn div(self, rhs: S) -> $name<S, U> {
+                $name(self.0 / rhs, core::marker::PhantomData)
             }
         }
-        impl<S: $crate::Scalar> core::ops::DivAssign<S> for $name<S> {
+        impl<S: $crate::Scalar, U> core::ops::DivAssign<S> for $name<S, U> {
             fn div_assign(&mut self, rhs: S) {
                 self.0 = self.0.clone() / rhs;
             }
         }
 
-        impl<S: $crate::Scalar> core::ops::Add<$name<S>> for $name<S> {
-            type Output = $name<S>;
-            fn add(self, rhs: $name<S>) -> $name<S> {
-                $name(self.0 + rhs.0)
+        impl<S: $crate::Scalar, U> $name<S, U> {
+            /// Multiplies this quantity by a scalar value, returning `None` on overflow.
+            #[inline]
+            pub fn checked_mul_scalar(self, rhs: S) -> Option<Self>
+            where
+                S: $crate::__num_traits::CheckedMul,
+            {
+                $crate::__num_traits::CheckedMul::checked_mul(&self.0, &rhs).map(|value| $name(value, core::marker::PhantomData))
+            }
+            /// Divides this quantity by a scalar value, returning `None` on overflow or division by zero.
+            #[inline]
+            pub fn checked_div_scalar(self, rhs: S) -> Option<Self>
+            where
+                S: $crate::__num_traits::CheckedDiv,
+            {
+                $crate::__num_traits::CheckedDiv::checked_div(&self.0, &rhs).map(|value| $name(value, core::marker::PhantomData))
+            }
+            /// Multiplies this quantity by a scalar value, saturating at the bounds of the scalar type.
+            #[inline]
+            pub fn saturating_mul_scalar(self, rhs: S) -> Self
+            where
+                S: $crate::__num_traits::SaturatingMul,
+            {
+                $name($crate::__num_traits::SaturatingMul::saturating_mul(&self.0, &rhs), core::marker::PhantomData)
+            }
+            /// Multiplies this quantity by a scalar value, wrapping around at the bounds of the scalar type.
+            #[inline]
+            pub fn wrapping_mul_scalar(self, rhs: S) -> Self
+            where
+                S: $crate::__num_traits::WrappingMul,
+            {
+                $name($crate::__num_traits::WrappingMul::wrapping_mul(&self.0, &rhs), core::marker::PhantomData)
             }
         }
-        impl<S: $crate::Scalar> core::ops::AddAssign<$name<S>> for $name<S> {
-            fn add_assign(&mut self, rhs: $name<S>) {
-                self.0 = self.0.clone() + rhs.0;
+
+        $crate::__dim_additive_imp!($name $(, $difference)?);
+
+        impl $name {
+            /// Lists the units declared with this dimension.
+            #[inline]
+            pub const fn units() -> &'static [$crate::units::UnitDescriptor] {
+                <Self as $crate::Dimension>::UNITS
             }
         }
-        impl<S: $crate::Scalar> core::ops::Sub<$name<S>> for $name<S> {
-            type Output = $name<S>;
-            fn sub(self, rhs: $name<S>) -> $name<S> {
-                $name(self.0 - rhs.0)
+
+        impl<S: $crate::Scalar + core::fmt::Display, U: $crate::units::UnitInfo> core::fmt::Display for $name<S, U> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&$crate::display::DisplayIn::<S, U>::new(self.0.clone()), f)
             }
         }
-        impl<S: $crate::Scalar> core::ops::SubAssign<$name<S>> for $name<S> {
-            fn sub_assign(&mut self, rhs: $name<S>) {
-                self.0 = self.0.clone() - rhs.0;
+
+        impl<S: $crate::Scalar, U: $crate::UnitOf<S, $name<S>>> $name<S, U> {
+            /// Creates a quantity stored as a value in the unit `U`.
+            ///
+            /// Quantities stored in a unit other than the canonical one keep the value exactly as given,
+            /// which makes them useful with integer scalars.
+            #[inline]
+            pub fn new(value: S) -> Self {
+                $name(value, core::marker::PhantomData)
+            }
+            /// Returns the stored value, in the unit `U`.
+            #[inline]
+            pub fn value(&self) -> S {
+                self.0.clone()
+            }
+            /// Converts the quantity to be stored in the unit `V`, rounding the value like other conversions do.
+            ///
+            /// Integer values are converted exactly and then truncated, without going through the canonical unit.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the result can't be represented by the scalar type, like 1 meter in `u8` millimeters.
+            /// Use [`try_convert`](Self::try_convert) to handle that case.
+            #[inline]
+            pub fn convert<V>(&self) -> $name<S, V>
+            where
+                U: $crate::TryUnitOf<S, $name<S>> + $crate::units::UnitInfo,
+                V: $crate::TryUnitOf<S, $name<S>> + $crate::units::UnitInfo,
+            {
+                self.try_convert().unwrap()
+            }
+            /// Converts the quantity to be stored in the unit `V`, rounding the value like other conversions do,
+            /// and failing if the result can't be represented by the scalar type.
+            #[inline]
+            pub fn try_convert<V>(&self) -> Result<$name<S, V>, $crate::units::ConversionError>
+            where
+                U: $crate::TryUnitOf<S, $name<S>> + $crate::units::UnitInfo,
+                V: $crate::TryUnitOf<S, $name<S>> + $crate::units::UnitInfo,
+            {
+                $crate::units::__convert_rounded::<S, $name<S>, U, V>(self.0.clone())
+                .map($name::<S, V>::new)
+            }
+            /// Converts the quantity to be stored in the unit `V`,
+            /// failing if the value can't be represented exactly by the scalar type.
+            ///
+            /// Conversions are done with the factors and offsets of both units,
+            /// so both units need a `FACTOR`, which every unit declared with `dimension!` or `simple_unit!` has.
+            pub fn checked_convert<V>(&self) -> Option<$name<S, V>>
+            where
+                U: $crate::TryUnitOf<S, $name<S>> + $crate::units::UnitInfo,
+                V: $crate::TryUnitOf<S, $name<S>> + $crate::units::UnitInfo,
+            {
+                $crate::units::__convert_exact::<S, $name<S>, U, V>(self.0.clone())
+                .map($name::<S, V>::new)
             }
         }
 
-        impl<S: $crate::Scalar> core::iter::Sum<$name<S>> for $name<S> where $name<S>: Default {
-            fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
-                iter.fold(
-                    $name::default(),
-                    |a, b| a + b,
-                )
+        impl<S: $crate::Scalar> $name<S> {
+            /// Creates a quantity from a value in the unit with the given symbol or name.
+            pub fn from_unit_name(value: S, unit: &str) -> Result<Self, $crate::units::UnitNameError> {
+                let index = $crate::units::__find_unit(<Self as $crate::Dimension<S>>::UNITS, stringify!($name), unit)?;
+                let to_canonical: &[fn(S) -> Result<S, $crate::units::ConversionError>] = &[$(<$unit as $crate::TryUnitOf<S, $name<S>>>::try_to_canonical),+];
+                Ok($name(to_canonical[index](value)?, core::marker::PhantomData))
+            }
+            /// Converts the quantity to the unit with the given symbol or name.
+            pub fn to_unit_name(&self, unit: &str) -> Result<S, $crate::units::UnitNameError> {
+                let index = $crate::units::__find_unit(<Self as $crate::Dimension<S>>::UNITS, stringify!($name), unit)?;
+                let from_canonical: &[fn(S) -> Result<S, $crate::units::ConversionError>] = &[$(<$unit as $crate::TryUnitOf<S, $name<S>>>::try_from_canonical),+];
+                Ok(from_canonical[index](self.0.clone())?)
+            }
+            /// Picks the metric unit that gives this quantity the most readable magnitude, for display.
+            #[inline]
+            pub fn humanize(&self) -> $crate::display::Humanized<S> {
+                self.humanize_in($crate::units::UnitSystem::Metric)
+            }
+            /// Picks the unit of the given system that gives this quantity the most readable magnitude, for display.
+            pub fn humanize_in(&self, system: $crate::units::UnitSystem) -> $crate::display::Humanized<S> {
+                let units = <Self as $crate::Dimension<S>>::UNITS;
+                let canonical = $crate::__num_traits::ToPrimitive::to_f64(&self.0).unwrap_or(core::f64::NAN);
+                let index = $crate::units::__humanize_unit(units, canonical, system);
+                let from_canonical: &[fn(S) -> Result<S, $crate::units::ConversionError>] = &[$(<$unit as $crate::TryUnitOf<S, $name<S>>>::try_from_canonical),+];
+                match from_canonical[index](self.0.clone()) {
+                    Ok(value) => $crate::display::Humanized::new(value, units[index]),
+                    // Fall back to the canonical unit when the value doesn't fit in the scalar type in the chosen one.
+                    Err(_) => $crate::display::Humanized::new(self.0.clone(), $crate::units::UnitDescriptor::of::<$canonical_unit>()),
+                }
+            }
+        }
+
+        impl $name<f64> {
+            /// The units of this dimension, for converting between them by name with [`convert_in`]($crate::units::convert_in).
+            pub const UNIT_TABLE: $crate::units::UnitTable = $crate::units::UnitTable {
+                dimension: stringify!($name),
+                units: <Self as $crate::Dimension<f64>>::UNITS,
+                convert: |value, from, to| $name::<f64>::from_unit_name(value, from)?.to_unit_name(to),
+            };
+        }
+
+        impl<S: $crate::Scalar> core::str::FromStr for $name<S> {
+            type Err = $crate::parse::ParseQuantityError;
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let to_canonical: &[fn(S) -> Result<S, $crate::units::ConversionError>] = &[$(<$unit as $crate::TryUnitOf<S, $name<S>>>::try_to_canonical),+];
+                $crate::parse::__parse_quantity(text, stringify!($name), <Self as $crate::Dimension<S>>::UNITS, to_canonical).map(|value| $name(value, core::marker::PhantomData))
             }
         }
 
         $(
             $crate::simple_unit!(
                 $(#[$unit_meta])*
-                $vis $unit of dimension $name = $($rhsper per canonical)? $(per $lhsper canonical)?
+                $vis $unit $([$symbol, $singular, $plural $(, $alias)*])? $(in $($system),+)? of dimension $name = $($rhsper per canonical)? $(per $lhsper canonical)? $(offset $offset)? $(zero at $zero)?
             );
         )*
 
+        $crate::__ratio_conversions!($name);
+
         $(
             $crate::__measure_conversions!($name, $($converts)*);
         )?
@@ -1106,10 +3064,33 @@ scalar_extension_trait!(
             kiloamperes => Kiloamperes
         },
 
+        SpecificEnergy {
+            joules_per_kilogram => JoulesPerKilogram,
+            kilojoules_per_kilogram => KilojoulesPerKilogram,
+            btus_per_pound => BtusPerPound
+        },
+
+        Ratio {
+            unity => Unity,
+            percent => Percent,
+            per_mille => PerMille,
+            parts_per_million => PartsPerMillion
+        },
+
         Temperature {
             kelvin => Kelvin,
             celsius => Celsius,
-            fahrenheit => Fahrenheit
+            fahrenheit => Fahrenheit,
+            rankine => Rankine,
+            reaumur => Reaumur
+        },
+
+        TemperatureInterval {
+            delta_kelvin => DeltaKelvin,
+            delta_celsius => DeltaCelsius,
+            delta_fahrenheit => DeltaFahrenheit,
+            delta_rankine => DeltaRankine,
+            delta_reaumur => DeltaReaumur
         }
     }
 );
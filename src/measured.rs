@@ -0,0 +1,217 @@
+//! Scalars with a standard uncertainty.
+//!
+//! [`Measured`] is a [`Scalar`](crate::Scalar) holding a value and its standard uncertainty, written `value ± sigma`.
+//! The uncertainty is propagated to first order through arithmetic, unit conversions and the relations between dimensions,
+//! treating the operands of every operation as independent.
+//!
+//! Propagating uncertainty needs square roots,
+//! so enable the `std` feature, or the `libm` feature on `no_std` targets.
+//!
+//! ```
+//! use shrewnit::{measured::Measured, prelude::*};
+//!
+//! let distance = Measured::new(16.0f64, 0.2) * Meters;
+//! let time = Measured::new(5.0, 0.05) * Seconds;
+//! let speed: LinearVelocity<Measured<f64>> = distance / time;
+//!
+//! let speed = speed.to::<MetersPerSecond>();
+//! assert!((speed.value() - 3.2).abs() < 1e-12);
+//! assert!((speed.sigma() - 0.0512).abs() < 1e-4);
+//!
+//! assert_eq!(format!("{:.2}", distance / time), "3.20 ± 0.05 m/s");
+//! assert_eq!(format!("{:.1}", distance.display_in::<Centimeters>()), "1600.0 ± 20.0 cm");
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+
+use crate::display::{write_number, write_padded_numbers};
+
+/// A value with a standard uncertainty, written `value ± sigma`.
+///
+/// Values converted from primitives, like conversion factors, are exact.
+/// Converting to primitives gives the value, without its uncertainty.
+///
+/// ```
+/// use shrewnit::measured::Measured;
+///
+/// let width = Measured::new(3.0f64, 0.3);
+/// let height = Measured::new(4.0, 0.4);
+///
+/// let area = width * height;
+/// assert_eq!(area.value(), 12.0);
+/// assert!((area.sigma() - 1.697).abs() < 0.001);
+/// assert!((area.relative_sigma() - 0.1414).abs() < 0.0001);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Measured<T> {
+    value: T,
+    sigma: T,
+}
+
+impl<T: Float> Measured<T> {
+    /// Creates a value with the given standard uncertainty.
+    ///
+    /// The sign of the uncertainty is ignored.
+    #[inline]
+    pub fn new(value: T, sigma: T) -> Self {
+        Self {
+            value,
+            sigma: sigma.abs(),
+        }
+    }
+
+    /// Creates a value without uncertainty.
+    #[inline]
+    pub fn exact(value: T) -> Self {
+        Self {
+            value,
+            sigma: T::zero(),
+        }
+    }
+
+    /// Returns the value.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the standard uncertainty of the value.
+    #[inline]
+    pub fn sigma(&self) -> T {
+        self.sigma
+    }
+
+    /// Returns the standard uncertainty relative to the magnitude of the value.
+    #[inline]
+    pub fn relative_sigma(&self) -> T {
+        self.sigma / self.value.abs()
+    }
+}
+
+impl<T: Float> Add for Measured<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value + rhs.value,
+            sigma: self.sigma.hypot(rhs.sigma),
+        }
+    }
+}
+
+impl<T: Float> Sub for Measured<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value - rhs.value,
+            sigma: self.sigma.hypot(rhs.sigma),
+        }
+    }
+}
+
+impl<T: Float> Mul for Measured<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value * rhs.value,
+            sigma: (self.sigma * rhs.value).hypot(self.value * rhs.sigma),
+        }
+    }
+}
+
+impl<T: Float> Div for Measured<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        Self {
+            value,
+            sigma: (self.sigma / rhs.value).hypot(value * rhs.sigma / rhs.value),
+        }
+    }
+}
+
+impl<T: Float> Neg for Measured<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            sigma: self.sigma,
+        }
+    }
+}
+
+impl<T: Float> PartialOrd for Measured<T> {
+    /// Compares the values.
+    ///
+    /// Equal values with different uncertainties aren't equal, so they're unordered.
+    ///
+    /// ```
+    /// use shrewnit::measured::Measured;
+    ///
+    /// let a = Measured::new(3.0f64, 0.1);
+    /// let b = Measured::new(3.0, 0.2);
+    ///
+    /// assert!(a < Measured::new(4.0, 0.2));
+    /// assert!(a <= Measured::new(3.0, 0.1));
+    /// assert_eq!(a.partial_cmp(&b), None);
+    /// assert!(!(a <= b) && !(a >= b) && a != b);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.value.partial_cmp(&other.value) {
+            Some(Ordering::Equal) if self.sigma != other.sigma => None,
+            ordering => ordering,
+        }
+    }
+}
+
+impl<T: Float> FromPrimitive for Measured<T> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from(n).map(Self::exact)
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from(n).map(Self::exact)
+    }
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from(n).map(Self::exact)
+    }
+}
+
+impl<T: Float> ToPrimitive for Measured<T> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.value.to_i64()
+    }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.value.to_u64()
+    }
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.value.to_f64()
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Measured<T> {
+    /// Writes `value ± sigma`, applying the sign flag to the value and the precision to both.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_padded_numbers(f, |w, sign_plus, precision| {
+            write_number(w, &self.value, sign_plus, precision)?;
+            w.write_str(" ± ")?;
+            write_number(w, &self.sigma, false, precision)
+        })
+    }
+}
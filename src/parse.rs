@@ -0,0 +1,241 @@
+//! Parsing quantities from text.
+//!
+//! Every dimension created with [`dimension!`](crate::dimension) implements [`FromStr`](core::str::FromStr).
+//! A quantity is written as a number followed by a unit, which may be separated by whitespace.
+//! Units can be written as their symbol (case sensitive) or their singular or plural name (ignoring ASCII case),
+//! and numbers can use scientific notation.
+//! Compound quantities like `5 ft 3 in` are parsed by adding their parts together,
+//! with a leading sign applying to the whole quantity, see the [`compound`](crate::compound) module.
+//! The other parts can't have a sign of their own.
+//!
+//! ```
+//! use shrewnit::{parse::ParseQuantityError, prelude::*, units::ConversionError};
+//!
+//! let length: Length = "5 ft".parse().unwrap();
+//! let speed: LinearVelocity = "3.2 km/h".parse().unwrap();
+//! let temperature: Temperature = "20 °C".parse().unwrap();
+//! let time: Time = "1.5e3 Milliseconds".parse().unwrap();
+//!
+//! assert_eq!(length, 5.0 * Feet);
+//! assert_eq!(time, 1.5 * Seconds);
+//!
+//! assert_eq!("five feet".parse::<Length>(), Err(ParseQuantityError::InvalidNumber));
+//! assert_eq!("5 furlongs".parse::<Length>(), Err(ParseQuantityError::UnknownUnit));
+//! assert_eq!("5".parse::<Length>(), Err(ParseQuantityError::MissingUnit));
+//! assert_eq!("5".parse::<Ratio>(), Ok(5.0 * Unity));
+//! assert_eq!("20 °C 5 °C".parse::<Temperature>(), Err(ParseQuantityError::AffineCompound));
+//! assert_eq!("-5 ft -3 in".parse::<Length>(), Err(ParseQuantityError::SignedPart));
+//! assert_eq!("9007199254740993 m".parse::<Length<i64>>(), Ok(9_007_199_254_740_993i64 * Meters));
+//! assert_eq!(
+//!     "10 km".parse::<Length<u8>>(),
+//!     Err(ParseQuantityError::Conversion(ConversionError::Overflow))
+//! );
+//! assert_eq!(
+//!     "5 kg".parse::<Length>(),
+//!     Err(ParseQuantityError::WrongDimension { expected: "Length", found: "Mass" })
+//! );
+//! ```
+
+use core::fmt;
+
+use crate::{
+    units::{
+        decimal_scalar, is_fractional, is_wide, parse_decimal, ConversionError, UnitDescriptor,
+        UnitNameError,
+    },
+    Scalar,
+};
+
+/// The error returned when parsing a quantity fails.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ParseQuantityError {
+    /// The text doesn't start with a valid number, or the number can't be represented by the scalar type.
+    InvalidNumber,
+    /// The number isn't followed by a unit, and the dimension has no unit without a symbol.
+    MissingUnit,
+    /// The unit isn't a unit of any known dimension.
+    UnknownUnit,
+    /// The unit is a unit of another dimension provided by Shrewnit.
+    WrongDimension {
+        /// The dimension that was being parsed.
+        expected: &'static str,
+        /// The dimension the unit belongs to.
+        found: &'static str,
+    },
+    /// A quantity with several parts used a unit with an offset, like `°C`, so its parts can't be added together.
+    AffineCompound,
+    /// A part of a quantity with several parts, other than the first, has a sign, like the `-3 in` of `-5 ft -3 in`.
+    SignedPart,
+    /// The quantity can't be converted to the canonical unit in the scalar type.
+    Conversion(ConversionError),
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseQuantityError::InvalidNumber => f.write_str("invalid number"),
+            ParseQuantityError::MissingUnit => f.write_str("missing unit"),
+            ParseQuantityError::UnknownUnit => f.write_str("unknown unit"),
+            ParseQuantityError::WrongDimension { expected, found } => write!(
+                f,
+                "expected a unit of {}, found a unit of {}",
+                expected, found
+            ),
+            ParseQuantityError::AffineCompound => {
+                f.write_str("units with an offset can't be combined")
+            }
+            ParseQuantityError::SignedPart => {
+                f.write_str("only the first part of a quantity can have a sign")
+            }
+            ParseQuantityError::Conversion(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseQuantityError {}
+
+impl From<ConversionError> for ParseQuantityError {
+    fn from(error: ConversionError) -> Self {
+        ParseQuantityError::Conversion(error)
+    }
+}
+
+impl From<UnitNameError> for ParseQuantityError {
+    fn from(error: UnitNameError) -> Self {
+        match error {
+            UnitNameError::MissingUnit => ParseQuantityError::MissingUnit,
+            UnitNameError::UnknownUnit => ParseQuantityError::UnknownUnit,
+            UnitNameError::WrongDimension { expected, found } => {
+                ParseQuantityError::WrongDimension { expected, found }
+            }
+            UnitNameError::Conversion(error) => ParseQuantityError::Conversion(error),
+        }
+    }
+}
+
+/// Parses a quantity with one or more parts into its canonical value.
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub fn __parse_quantity<S: Scalar>(
+    text: &str,
+    dimension: &'static str,
+    units: &[UnitDescriptor],
+    to_canonical: &[fn(S) -> Result<S, ConversionError>],
+) -> Result<S, ParseQuantityError> {
+    let mut total: Option<S> = None;
+    let mut negative = false;
+    let mut rest = text;
+    loop {
+        if total.is_some() {
+            if let Some(b'+') | Some(b'-') = rest.as_bytes().first() {
+                return Err(ParseQuantityError::SignedPart);
+            }
+        }
+        let (number, unit, next) = __split_quantity(rest)?;
+        // A leading sign applies to the whole quantity, so `-5 ft 3 in` is `-(5 ft + 3 in)`.
+        if total.is_none() {
+            negative = number.starts_with('-');
+        }
+        let value = parse_number::<S>(number, total.is_some() && negative)?;
+        let index = crate::units::__find_unit(units, dimension, unit)?;
+        if (total.is_some() || !next.is_empty()) && units[index].offset != 0.0 {
+            return Err(ParseQuantityError::AffineCompound);
+        }
+
+        let value = to_canonical[index](value)?;
+        let sum = match total {
+            Some(total) => total + value,
+            None => value,
+        };
+        if next.is_empty() {
+            return Ok(sum);
+        }
+        total = Some(sum);
+        rest = next;
+    }
+}
+
+/// Parses a number into a scalar, negating it if `negate` is set.
+///
+/// Integers and scalars that hold more digits than an `f64`, like decimals, are given the digits as they're written,
+/// so they aren't rounded to an `f64` first. Other scalars, like floats, are parsed through an `f64`.
+fn parse_number<S: Scalar>(text: &str, negate: bool) -> Result<S, ParseQuantityError> {
+    if !is_fractional::<S>() || is_wide::<S>() {
+        let exact = parse_decimal(text).and_then(|(mantissa, scale)| {
+            decimal_scalar((if negate { -mantissa } else { mantissa }, scale))
+        });
+        if let Some(exact) = exact {
+            return Ok(exact);
+        }
+    }
+    let value: f64 = text
+        .parse()
+        .map_err(|_| ParseQuantityError::InvalidNumber)?;
+    S::from_f64(if negate { -value } else { value }).ok_or(ParseQuantityError::InvalidNumber)
+}
+
+/// Splits text into its first number, the unit following it, and the rest of the text.
+///
+/// The unit ends at the first whitespace followed by another number.
+#[doc(hidden)]
+pub fn __split_quantity(text: &str) -> Result<(&str, &str, &str), ParseQuantityError> {
+    let text = text.trim();
+    let bytes = text.as_bytes();
+    let is_digit = |i: usize| bytes.get(i).map_or(false, u8::is_ascii_digit);
+
+    let mut end = 0;
+    if let Some(b'+') | Some(b'-') = bytes.get(end) {
+        end += 1;
+    }
+    while is_digit(end) || bytes.get(end) == Some(&b'.') {
+        end += 1;
+    }
+    if let Some(b'e') | Some(b'E') = bytes.get(end) {
+        let sign = match bytes.get(end + 1) {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+        if is_digit(end + 1 + sign) {
+            end += 1 + sign;
+            while is_digit(end) {
+                end += 1;
+            }
+        }
+    }
+
+    let number = &text[..end];
+    if number.parse::<f64>().is_err() {
+        return Err(ParseQuantityError::InvalidNumber);
+    }
+
+    let starts_number = |i: usize| match bytes.get(i) {
+        Some(b'+') | Some(b'-') => {
+            is_digit(i + 1) || (bytes.get(i + 1) == Some(&b'.') && is_digit(i + 2))
+        }
+        Some(b'.') => is_digit(i + 1),
+        _ => is_digit(i),
+    };
+    let mut unit_end = end;
+    while unit_end < bytes.len() {
+        if bytes[unit_end].is_ascii_whitespace() {
+            let next = unit_end
+                + text[unit_end..]
+                    .bytes()
+                    .take_while(u8::is_ascii_whitespace)
+                    .count();
+            if starts_number(next) {
+                break;
+            }
+            unit_end = next;
+        } else {
+            unit_end += 1;
+        }
+    }
+
+    Ok((
+        number,
+        text[end..unit_end].trim(),
+        text[unit_end..].trim_start(),
+    ))
+}
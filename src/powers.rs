@@ -0,0 +1,115 @@
+//! Powers and roots of quantities.
+//!
+//! The [`Powers`] trait is implemented for every dimension with known [exponents](crate::exponents),
+//! and maps the result of a power or root to the dimension type Shrewnit provides for it.
+//! Powers and roots without a matching dimension type are available on [`Quantity`](crate::quantity::Quantity).
+//!
+//! Roots and arbitrary integer powers need floating point math.
+//! Enable the `std` feature to use the float methods from `std`, or the `libm` feature on `no_std` targets.
+
+use crate::{
+    exponents::{Cube, DimMul, Dimensioned, Named, NamedDimension, Square},
+    Dimension, Scalar,
+};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::exponents::{Cbrt, DimCbrt, DimPow, DimSqrt, Integer, Pow, Sqrt};
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+
+/// Powers and roots of a quantity.
+///
+/// # Examples
+///
+/// ```
+/// use shrewnit::prelude::*;
+///
+/// let side = 3.0f64 * Meters;
+/// let area: Area = side.squared();
+/// let volume: Volume = side.cubed();
+///
+/// // Kinetic energy is half of the mass times the velocity squared.
+/// let kinetic_energy: Energy = (2.0f64 * MetersPerSecond).squared() * (3.0 * Kilograms) / 2.0;
+/// assert_eq!(kinetic_energy.to::<Joules>(), 6.0);
+/// ```
+pub trait Powers<S: Scalar>: Dimension<S> + Dimensioned + Sized {
+    /// Multiplies this quantity by itself.
+    #[inline]
+    fn squared(self) -> Named<Square<Self::Exponents>, S>
+    where
+        Self::Exponents: DimMul<Self::Exponents>,
+        Square<Self::Exponents>: NamedDimension<S>,
+    {
+        let value = self.canonical();
+        Dimension::from_canonical(value.clone() * value)
+    }
+
+    /// Multiplies this quantity by itself twice.
+    #[inline]
+    fn cubed(self) -> Named<Cube<Self::Exponents>, S>
+    where
+        Self::Exponents: DimMul<Self::Exponents>,
+        Square<Self::Exponents>: DimMul<Self::Exponents>,
+        Cube<Self::Exponents>: NamedDimension<S>,
+    {
+        let value = self.canonical();
+        Dimension::from_canonical(value.clone() * value.clone() * value)
+    }
+
+    /// Raises this quantity to the type-level integer power `E`.
+    ///
+    /// ```
+    /// use shrewnit::{exponents::P2, prelude::*};
+    ///
+    /// let area: Area = (4.0f64 * Meters).powi::<P2>();
+    /// assert_eq!(area.to::<SquareMeters>(), 16.0);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn powi<E: Integer>(self) -> Named<Pow<Self::Exponents, E>, S>
+    where
+        S: Float,
+        Self::Exponents: DimPow<E>,
+        Pow<Self::Exponents, E>: NamedDimension<S>,
+    {
+        Dimension::from_canonical(self.canonical().powi(E::VALUE as i32))
+    }
+
+    /// Takes the square root of this quantity.
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// let side: Length = (16.0f64 * SquareMeters).sqrt();
+    /// assert_eq!(side.to::<Meters>(), 4.0);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn sqrt(self) -> Named<Sqrt<Self::Exponents>, S>
+    where
+        S: Float,
+        Self::Exponents: DimSqrt,
+        Sqrt<Self::Exponents>: NamedDimension<S>,
+    {
+        Dimension::from_canonical(self.canonical().sqrt())
+    }
+
+    /// Takes the cube root of this quantity.
+    ///
+    /// ```
+    /// use shrewnit::prelude::*;
+    ///
+    /// let side: Length = (27.0f64 * CubicMeters).cbrt();
+    /// assert_eq!(side.to::<Meters>(), 3.0);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn cbrt(self) -> Named<Cbrt<Self::Exponents>, S>
+    where
+        S: Float,
+        Self::Exponents: DimCbrt,
+        Cbrt<Self::Exponents>: NamedDimension<S>,
+    {
+        Dimension::from_canonical(self.canonical().cbrt())
+    }
+}
+impl<S: Scalar, D: Dimension<S> + Dimensioned> Powers<S> for D {}
@@ -0,0 +1,254 @@
+//! A single generic quantity type whose dimension is tracked by [type-level exponents](crate::exponents).
+//!
+//! The per-dimension types in [`dimensions`](crate::dimensions) only support the products and quotients
+//! that have been declared for them. [`Quantity`] supports every product and quotient,
+//! computing the dimension of the result from the exponents of its operands.
+//!
+//! Any dimension declared with an `exponents` line converts losslessly to and from its `Quantity` form.
+//!
+//! ```
+//! # use shrewnit::prelude::*;
+//! use shrewnit::quantity::Quantity;
+//!
+//! let distance = Quantity::from(100.0 * Meters);
+//! let time = Quantity::from(20.0 * Seconds);
+//!
+//! // `Length * Time` has no dimension type, but it is still a valid quantity.
+//! let absement = distance * time;
+//! let velocity: LinearVelocity = (absement / time / time).into();
+//!
+//! assert_eq!(velocity.to::<MetersPerSecond>(), 5.0);
+//! ```
+
+#![allow(clippy::non_canonical_clone_impl)]
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use crate::{
+    exponents::{Cube, DimDiv, DimMul, Exponents, NamedDimension, Square},
+    Dimension, Scalar,
+};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::exponents::{Cbrt, DimCbrt, DimPow, DimSqrt, Integer, Pow, Sqrt};
+
+/// A quantity with the dimension described by the exponents `D`.
+///
+/// The value is stored in the coherent SI unit of the dimension,
+/// which is the canonical unit of every dimension provided by Shrewnit.
+pub struct Quantity<S: Scalar, D> {
+    value: S,
+    dimension: PhantomData<D>,
+}
+
+impl<S: Scalar, D> Quantity<S, D> {
+    /// Creates a new quantity from a value in the coherent SI unit of its dimension.
+    #[cfg(feature = "const_operators")]
+    #[inline]
+    pub const fn new(value: S) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
+    /// Creates a new quantity from a value in the coherent SI unit of its dimension.
+    #[cfg(not(feature = "const_operators"))]
+    #[inline]
+    pub fn new(value: S) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Returns the value of this quantity in the coherent SI unit of its dimension.
+    #[inline]
+    pub fn value(&self) -> S {
+        self.value.clone()
+    }
+
+    /// Returns the value of this quantity in the coherent SI unit of its dimension.
+    #[inline]
+    pub fn into_value(self) -> S {
+        self.value
+    }
+}
+
+impl<S: Scalar, D: NamedDimension<S>> Quantity<S, D> {
+    /// Converts this quantity into the dimension type Shrewnit provides for it.
+    #[inline]
+    pub fn into_dimension(self) -> D::Dimension {
+        Dimension::from_canonical(self.value)
+    }
+}
+
+impl<S: Scalar, D> Quantity<S, D> {
+    /// Multiplies this quantity by itself.
+    #[inline]
+    pub fn squared(self) -> Quantity<S, Square<D>>
+    where
+        D: DimMul<D>,
+    {
+        Quantity::new(self.value.clone() * self.value)
+    }
+
+    /// Multiplies this quantity by itself twice.
+    #[inline]
+    pub fn cubed(self) -> Quantity<S, Cube<D>>
+    where
+        D: DimMul<D>,
+        Square<D>: DimMul<D>,
+    {
+        Quantity::new(self.value.clone() * self.value.clone() * self.value)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S: Scalar + num_traits::Float, D> Quantity<S, D> {
+    /// Raises this quantity to the type-level integer power `E`.
+    ///
+    /// ```
+    /// # use shrewnit::prelude::*;
+    /// use shrewnit::{exponents::N2, quantity::Quantity};
+    ///
+    /// let per_square_second = Quantity::from(2.0 * Seconds).powi::<N2>();
+    /// assert_eq!(per_square_second.value(), 0.25);
+    /// ```
+    #[inline]
+    pub fn powi<E: Integer>(self) -> Quantity<S, Pow<D, E>>
+    where
+        D: DimPow<E>,
+    {
+        Quantity::new(self.value.powi(E::VALUE as i32))
+    }
+
+    /// Takes the square root of this quantity.
+    #[inline]
+    pub fn sqrt(self) -> Quantity<S, Sqrt<D>>
+    where
+        D: DimSqrt,
+    {
+        Quantity::new(self.value.sqrt())
+    }
+
+    /// Takes the cube root of this quantity.
+    #[inline]
+    pub fn cbrt(self) -> Quantity<S, Cbrt<D>>
+    where
+        D: DimCbrt,
+    {
+        Quantity::new(self.value.cbrt())
+    }
+}
+
+impl<S: Scalar + Copy, D> Copy for Quantity<S, D> {}
+impl<S: Scalar, D> Clone for Quantity<S, D> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+impl<S: Scalar + Default, D> Default for Quantity<S, D> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+impl<S: Scalar + PartialEq, D> PartialEq for Quantity<S, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<S: Scalar + Eq, D> Eq for Quantity<S, D> {}
+impl<S: Scalar + PartialOrd, D> PartialOrd for Quantity<S, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+impl<S: Scalar + Ord, D> Ord for Quantity<S, D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+impl<S: Scalar + Hash, D> Hash for Quantity<S, D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<S: Scalar + fmt::Debug, D: Exponents> fmt::Debug for Quantity<S, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Quantity({:?} {:?})", self.value, D::EXPONENTS)
+    }
+}
+
+impl<S: Scalar, D> Add for Quantity<S, D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value)
+    }
+}
+impl<S: Scalar, D> AddAssign for Quantity<S, D> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value = self.value.clone() + rhs.value;
+    }
+}
+impl<S: Scalar, D> Sub for Quantity<S, D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value)
+    }
+}
+impl<S: Scalar, D> SubAssign for Quantity<S, D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value = self.value.clone() - rhs.value;
+    }
+}
+
+impl<S: Scalar, D> Mul<S> for Quantity<S, D> {
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self {
+        Self::new(self.value * rhs)
+    }
+}
+impl<S: Scalar, D> MulAssign<S> for Quantity<S, D> {
+    fn mul_assign(&mut self, rhs: S) {
+        self.value = self.value.clone() * rhs;
+    }
+}
+impl<S: Scalar, D> Div<S> for Quantity<S, D> {
+    type Output = Self;
+    fn div(self, rhs: S) -> Self {
+        Self::new(self.value / rhs)
+    }
+}
+impl<S: Scalar, D> DivAssign<S> for Quantity<S, D> {
+    fn div_assign(&mut self, rhs: S) {
+        self.value = self.value.clone() / rhs;
+    }
+}
+
+impl<S: Scalar, D1: DimMul<D2>, D2> Mul<Quantity<S, D2>> for Quantity<S, D1> {
+    type Output = Quantity<S, D1::Output>;
+    fn mul(self, rhs: Quantity<S, D2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+impl<S: Scalar, D1: DimDiv<D2>, D2> Div<Quantity<S, D2>> for Quantity<S, D1> {
+    type Output = Quantity<S, D1::Output>;
+    fn div(self, rhs: Quantity<S, D2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+impl<S: Scalar, D> core::iter::Sum for Quantity<S, D>
+where
+    Self: Default,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |a, b| a + b)
+    }
+}
@@ -0,0 +1,1058 @@
+//! Runtime descriptions of units.
+//!
+//! Every unit declared in a [`dimension!`](crate::dimension) implements [`UnitInfo`],
+//! which holds its symbol, names and conversion factor. These are used when parsing and formatting quantities.
+//!
+//! Each dimension also keeps a static table of [`UnitDescriptor`]s for its units,
+//! so they can be listed at runtime without allocating.
+//!
+//! Units can be tagged with the [`UnitSystem`]s they belong to,
+//! which lets `humanize` pick a readable unit without mixing metric and imperial units.
+
+use core::fmt;
+
+use crate::{Dimension, Scalar, TryUnitOf};
+
+/// The symbol, names and conversion factor of a unit.
+///
+/// This is implemented for every unit declared with [`dimension!`](crate::dimension) or [`simple_unit!`](crate::simple_unit),
+/// and for units declared with [`unit_type!`](crate::unit_type) that have metadata.
+/// Units declared without metadata use the name of their type as their symbol and names.
+///
+/// ```
+/// use shrewnit::{units::UnitInfo, Celsius, Feet};
+///
+/// assert_eq!(Feet::SYMBOL, "ft");
+/// assert_eq!(Feet::NAME, "foot");
+/// assert_eq!(Feet::PLURAL, "feet");
+/// assert_eq!(Feet::FACTOR, Some(0.3048));
+///
+/// assert_eq!(Celsius::OFFSET, 273.15);
+/// ```
+pub trait UnitInfo {
+    /// The symbol of the unit, like `"m"`.
+    const SYMBOL: &'static str;
+    /// The singular name of the unit, like `"meter"`.
+    const NAME: &'static str = Self::SYMBOL;
+    /// The plural name of the unit, like `"meters"`.
+    const PLURAL: &'static str = Self::NAME;
+    /// Other symbols or names the unit is known by, like `"metre"`.
+    const ALIASES: &'static [&'static str] = &[];
+    /// The amount of canonical units in one of this unit.
+    ///
+    /// This is `None` for units with conversions that aren't linear or affine,
+    /// such as units created with [`unit_type!`](crate::unit_type) and a manual [`UnitOf`](crate::UnitOf) implementation.
+    const FACTOR: Option<f64> = None;
+    /// The value of zero in this unit, in canonical units.
+    ///
+    /// A value `x` in this unit is `x * FACTOR + OFFSET` in the canonical unit.
+    const OFFSET: f64 = 0.0;
+    /// The systems of units this unit is commonly used in.
+    const SYSTEMS: &'static [UnitSystem] = &[];
+}
+
+/// A system of units, used to choose between units when humanizing a quantity.
+///
+/// ```
+/// use shrewnit::{units::{UnitInfo, UnitSystem}, Feet, Meters, NauticalMiles, Seconds};
+///
+/// assert_eq!(Meters::SYSTEMS, &[UnitSystem::Metric]);
+/// assert_eq!(Feet::SYSTEMS, &[UnitSystem::Imperial]);
+/// assert_eq!(Seconds::SYSTEMS, &[UnitSystem::Metric, UnitSystem::Imperial]);
+/// assert!(NauticalMiles::SYSTEMS.is_empty());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum UnitSystem {
+    /// The metric system, including SI units and their prefixes.
+    Metric,
+    /// Imperial and US customary units.
+    Imperial,
+}
+
+/// The symbol, names and conversion factor of a unit, for use when the unit is only known at runtime.
+///
+/// Every dimension lists the descriptors of the units declared with it in [`Dimension::UNITS`](crate::Dimension::UNITS).
+///
+/// ```
+/// use shrewnit::prelude::*;
+///
+/// for unit in Length::units() {
+///     println!("{} ({})", unit.plural, unit.symbol);
+/// }
+///
+/// let feet = Length::units().iter().find(|unit| unit.symbol == "ft").unwrap();
+/// assert_eq!(feet.factor, Some(0.3048));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct UnitDescriptor {
+    /// The symbol of the unit, like `"m"`.
+    pub symbol: &'static str,
+    /// The singular name of the unit, like `"meter"`.
+    pub name: &'static str,
+    /// The plural name of the unit, like `"meters"`.
+    pub plural: &'static str,
+    /// Other symbols or names the unit is known by, like `"metre"`.
+    pub aliases: &'static [&'static str],
+    /// The amount of canonical units in one of this unit, if the unit has a linear or affine conversion.
+    pub factor: Option<f64>,
+    /// The value of zero in this unit, in canonical units.
+    pub offset: f64,
+    /// The systems of units this unit is commonly used in.
+    pub systems: &'static [UnitSystem],
+}
+
+/// Describes a unit in a constant, which can't call [`UnitDescriptor::of`] without the `const_operators` feature.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_descriptor {
+    ($unit:ty) => {
+        $crate::units::UnitDescriptor {
+            symbol: <$unit as $crate::units::UnitInfo>::SYMBOL,
+            name: <$unit as $crate::units::UnitInfo>::NAME,
+            plural: <$unit as $crate::units::UnitInfo>::PLURAL,
+            aliases: <$unit as $crate::units::UnitInfo>::ALIASES,
+            factor: <$unit as $crate::units::UnitInfo>::FACTOR,
+            offset: <$unit as $crate::units::UnitInfo>::OFFSET,
+            systems: <$unit as $crate::units::UnitInfo>::SYSTEMS,
+        }
+    };
+}
+
+impl UnitDescriptor {
+    /// Describes the unit `U`.
+    #[cfg(feature = "const_operators")]
+    #[inline]
+    pub const fn of<U: UnitInfo>() -> Self {
+        crate::__unit_descriptor!(U)
+    }
+    /// Describes the unit `U`.
+    #[cfg(not(feature = "const_operators"))]
+    #[inline]
+    pub fn of<U: UnitInfo>() -> Self {
+        crate::__unit_descriptor!(U)
+    }
+
+    /// Returns `true` if `text` is the symbol of this unit.
+    ///
+    /// Symbols are case sensitive, so `"mm"` is not the same unit as `"Mm"`.
+    #[inline]
+    pub fn is_symbol(&self, text: &str) -> bool {
+        self.symbol == text
+    }
+
+    /// Returns `true` if `text` is the name, plural name or an alias of this unit, ignoring ASCII case.
+    #[inline]
+    pub fn is_name(&self, text: &str) -> bool {
+        self.name.eq_ignore_ascii_case(text)
+            || self.plural.eq_ignore_ascii_case(text)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(text))
+    }
+
+    /// Returns `true` if this unit converts to the canonical unit by multiplication alone.
+    #[inline]
+    pub fn is_linear(&self) -> bool {
+        self.offset == 0.0 && self.factor.map_or(false, |factor| factor > 0.0)
+    }
+}
+
+/// The error returned when a unit can't be found by its symbol or name.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum UnitNameError {
+    /// No unit was given, and the dimension has no unit without a symbol.
+    MissingUnit,
+    /// The unit isn't a unit of any known dimension.
+    UnknownUnit,
+    /// The unit is a unit of another dimension provided by Shrewnit.
+    WrongDimension {
+        /// The dimension the unit was expected to belong to.
+        expected: &'static str,
+        /// The dimension the unit belongs to.
+        found: &'static str,
+    },
+    /// The unit was found, but the value can't be converted to or from it in the scalar type.
+    Conversion(ConversionError),
+}
+
+impl From<ConversionError> for UnitNameError {
+    fn from(error: ConversionError) -> Self {
+        UnitNameError::Conversion(error)
+    }
+}
+
+impl fmt::Display for UnitNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitNameError::MissingUnit => f.write_str("missing unit"),
+            UnitNameError::UnknownUnit => f.write_str("unknown unit"),
+            UnitNameError::WrongDimension { expected, found } => write!(
+                f,
+                "expected a unit of {}, found a unit of {}",
+                expected, found
+            ),
+            UnitNameError::Conversion(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitNameError {}
+
+/// The units of a dimension, for converting between them by name with [`convert_in`].
+///
+/// Every dimension created with [`dimension!`](crate::dimension) provides its table as `UNIT_TABLE`.
+#[derive(Clone, Copy)]
+pub struct UnitTable {
+    /// The name of the dimension.
+    pub dimension: &'static str,
+    /// The units of the dimension.
+    pub units: &'static [UnitDescriptor],
+    /// Converts a value between two units of the dimension, given by their symbols or names.
+    pub convert: fn(f64, &str, &str) -> Result<f64, UnitNameError>,
+}
+
+impl fmt::Debug for UnitTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnitTable")
+            .field("dimension", &self.dimension)
+            .field("units", &self.units)
+            .finish()
+    }
+}
+
+/// The error returned when a value can't be converted to or from a unit.
+///
+/// Float scalars can represent every converted value, so this is only returned for other scalar types.
+///
+/// ```
+/// # use shrewnit::prelude::*;
+/// use shrewnit::units::ConversionError;
+///
+/// assert_eq!((3u8 * Meters).try_to::<Millimeters>(), Err(ConversionError::Overflow));
+/// assert_eq!(Length::<u8>::try_from_scalar::<Inches>(200), Ok(Length::<u8>::from_canonical(5)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ConversionError {
+    /// The converted value is outside the range of the scalar type.
+    Overflow,
+    /// The converted value is in range, but can't be represented by the scalar type.
+    NotRepresentable,
+    /// The converted value is NaN, which the scalar type can't represent.
+    NaN,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::Overflow => f.write_str("converted value is out of range"),
+            ConversionError::NotRepresentable => {
+                f.write_str("converted value can't be represented by the scalar type")
+            }
+            ConversionError::NaN => f.write_str("converted value is NaN"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+/// Converts a value calculated as an `f64` into a scalar, explaining why it doesn't fit if it can't be converted.
+fn try_from_f64<S: Scalar>(value: f64) -> Result<S, ConversionError> {
+    if let Some(scalar) = S::from_f64(value) {
+        return Ok(scalar);
+    }
+    if value.is_nan() {
+        return Err(ConversionError::NaN);
+    }
+
+    // Every float at least this large is already a whole number.
+    const WHOLE: f64 = 4_503_599_627_370_496.0;
+    let whole = if -WHOLE < value && value < WHOLE {
+        value as i64 as f64
+    } else {
+        value
+    };
+    // A nearby whole number that fits means the value is in range, just not representable.
+    if whole.is_finite() && S::from_f64(whole).is_some() {
+        Err(ConversionError::NotRepresentable)
+    } else {
+        Err(ConversionError::Overflow)
+    }
+}
+
+/// Whether `S` can hold fractions, like floats, decimals and fixed point numbers can.
+///
+/// Floats are compared by their bits here and in [`__try_convert`],
+/// so scalars without hardware float support, like fixed point numbers, don't need soft float routines.
+#[inline]
+pub(crate) fn is_fractional<S: Scalar>() -> bool {
+    S::from_f64(0.5)
+        .and_then(|half| half.to_f64())
+        .map(f64::to_bits)
+        == Some(0.5f64.to_bits())
+}
+
+/// Whether a value is zero, checked by its bits so scalars without hardware float support don't need soft float routines.
+#[inline]
+fn is_zero<S: Scalar>(value: &S) -> bool {
+    value.to_f64().map_or(false, |value| value.to_bits() << 1 == 0)
+}
+
+/// A number from the declaration of a unit, like a conversion factor or an offset.
+///
+/// Created as a constant by [`simple_unit!`](crate::simple_unit), so its reciprocal is calculated by the compiler,
+/// and scalars without hardware float support, like fixed point numbers, are converted without float arithmetic.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct __Factor {
+    /// The number as a float.
+    pub value: f64,
+    /// One divided by the number.
+    pub reciprocal: f64,
+    /// The number as it's written, like `"0.3048"`.
+    pub text: &'static str,
+}
+
+impl __Factor {
+    /// A factor of one.
+    pub const ONE: Self = Self {
+        value: 1.0,
+        reciprocal: 1.0,
+        text: "1.0",
+    };
+    /// An offset of zero.
+    pub const ZERO: Self = Self {
+        value: 0.0,
+        reciprocal: core::f64::INFINITY,
+        text: "0.0",
+    };
+}
+
+/// Creates a [`__Factor`](crate::units::__Factor) constant from a number in the declaration of a unit.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __factor {
+    ($value:expr) => {{
+        #[allow(clippy::excessive_precision)]
+        const FACTOR: $crate::units::__Factor = $crate::units::__Factor {
+            value: $value,
+            reciprocal: 1.0 / $value,
+            text: stringify!($value),
+        };
+        FACTOR
+    }};
+}
+
+/// Converts a value between units as `(value - before) * multiply / divide + after`.
+///
+/// Scalars that can hold fractions are converted with their own arithmetic, so they keep any precision beyond an `f64`.
+/// Other scalars, like integers, are converted through an `f64` and truncated.
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert<S: Scalar>(
+    value: S,
+    before: __Factor,
+    multiply: __Factor,
+    divide: __Factor,
+    after: __Factor,
+) -> Result<S, ConversionError> {
+    let (zero, one) = (0.0f64.to_bits(), 1.0f64.to_bits());
+    if !is_fractional::<S>() {
+        // The canonical unit keeps integers beyond the precision of an `f64`, like an `i64` of 2^53 + 1 meters.
+        if before.value.to_bits() == zero
+            && multiply.value.to_bits() == one
+            && divide.value.to_bits() == one
+            && after.value.to_bits() == zero
+        {
+            return Ok(value);
+        }
+        let value = value.to_f64().ok_or(ConversionError::NotRepresentable)?;
+        return try_from_f64((value - before.value) * multiply.value / divide.value + after.value);
+    }
+
+    let mut value = value;
+    if before.value.to_bits() != zero {
+        value = value - factor(before)?;
+    }
+    if multiply.value.to_bits() != one {
+        value = scale(value, multiply, true)?;
+    }
+    if divide.value.to_bits() != one {
+        value = scale(value, divide, false)?;
+    }
+    if after.value.to_bits() != zero {
+        value = value + factor(after)?;
+    }
+    Ok(value)
+}
+
+/// Converts a value between units as [`__try_convert`] does, for a unit whose canonical zero is at `zero` in the unit,
+/// declared with `zero at`. `multiply` and `divide` scale a value from the unit to the canonical one if `to_canonical` is set,
+/// and from the canonical unit to this one otherwise. `offset` is the zero of the unit in canonical units.
+///
+/// Scalars that hold more digits than an `f64`, like decimals, apply the zero as written, so conversions stay exact.
+/// Other scalars apply the offset, which rounds the same way as units declared with `offset`.
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert_zero<S: Scalar>(
+    value: S,
+    zero: __Factor,
+    offset: __Factor,
+    multiply: __Factor,
+    divide: __Factor,
+    to_canonical: bool,
+) -> Result<S, ConversionError> {
+    match (to_canonical, is_wide::<S>()) {
+        (true, true) => __try_convert(value, zero, multiply, divide, __Factor::ZERO),
+        (true, false) => __try_convert(value, __Factor::ZERO, multiply, divide, offset),
+        (false, true) => __try_convert(value, __Factor::ZERO, multiply, divide, zero),
+        (false, false) => __try_convert(value, offset, multiply, divide, __Factor::ZERO),
+    }
+}
+
+/// Converts a conversion factor into a scalar.
+///
+/// Scalars that hold more digits than an `f64`, like decimals, are given the factor as it's written,
+/// which makes factors like `0.3048` exact.
+#[inline]
+fn factor<S: Scalar>(factor: __Factor) -> Result<S, ConversionError> {
+    if is_wide::<S>() {
+        if let Some(decimal) = parse_decimal(factor.text).and_then(decimal_scalar) {
+            return Ok(decimal);
+        }
+    }
+    S::from_f64(factor.value).ok_or(ConversionError::NotRepresentable)
+}
+
+/// Whether `S` holds whole numbers beyond the precision of an `f64`, like decimals and wide fixed point numbers do.
+#[inline]
+pub(crate) fn is_wide<S: Scalar>() -> bool {
+    const BEYOND_F64: u64 = (1 << 53) + 1;
+    S::from_u64(BEYOND_F64).and_then(|wide| wide.to_u64()) == Some(BEYOND_F64)
+}
+
+/// Splits a decimal number, like `1_000.0` or `1.5e-7`, into its digits and the power of ten they're divided by.
+///
+/// Returns `None` if the text isn't a decimal number, or its digits don't fit in an `i128`.
+pub(crate) fn parse_decimal(text: &str) -> Option<(i128, u32)> {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+    let negative = match bytes.first() {
+        Some(b'-') => true,
+        _ => false,
+    };
+    if let Some(b'+') | Some(b'-') = bytes.first() {
+        index += 1;
+    }
+
+    let mut mantissa: i128 = 0;
+    let mut scale: u32 = 0;
+    let mut digits = 0;
+    let mut fraction = false;
+    while let Some(&digit) = bytes.get(index) {
+        match digit {
+            b'0'..=b'9' => {
+                mantissa = mantissa
+                    .checked_mul(10)?
+                    .checked_add(i128::from(digit - b'0'))?;
+                digits += 1;
+                if fraction {
+                    scale += 1;
+                }
+            }
+            b'_' => {}
+            b'.' if !fraction => fraction = true,
+            _ => break,
+        }
+        index += 1;
+    }
+    if digits == 0 {
+        return None;
+    }
+
+    if let Some(b'e') | Some(b'E') = bytes.get(index) {
+        index += 1;
+        let negative_exponent = bytes.get(index) == Some(&b'-');
+        if let Some(b'+') | Some(b'-') = bytes.get(index) {
+            index += 1;
+        }
+        let (mut exponent, mut exponent_digits) = (0u32, 0);
+        while let Some(&digit) = bytes.get(index) {
+            match digit {
+                b'0'..=b'9' => {
+                    exponent = exponent
+                        .checked_mul(10)?
+                        .checked_add(u32::from(digit - b'0'))?;
+                    exponent_digits += 1;
+                }
+                b'_' => {}
+                _ => break,
+            }
+            index += 1;
+        }
+        if exponent_digits == 0 {
+            return None;
+        }
+        if negative_exponent {
+            scale = scale.checked_add(exponent)?;
+        } else {
+            // Cancel decimal places first, so `1.5e3` is `1500` rather than `15000 / 10`.
+            let cancelled = if exponent < scale { exponent } else { scale };
+            scale -= cancelled;
+            mantissa = mantissa.checked_mul(10i128.checked_pow(exponent - cancelled)?)?;
+        }
+    }
+
+    // Literals can end with the type of float they are.
+    match &text[index..] {
+        "" | "f64" | "f32" => Some((if negative { -mantissa } else { mantissa }, scale)),
+        _ => None,
+    }
+}
+
+/// Converts the digits of a decimal number and the power of ten they're divided by into a scalar.
+///
+/// Returns `None` if the scalar can't hold the digits or the power of ten.
+pub(crate) fn decimal_scalar<S: Scalar>((mantissa, scale): (i128, u32)) -> Option<S> {
+    let value = S::from_i128(mantissa)?;
+    if scale == 0 {
+        return Some(value);
+    }
+    Some(value / S::from_i128(10i128.checked_pow(scale)?)?)
+}
+
+/// The digits of a float formatted as its shortest decimal, collected without allocating.
+struct Digits {
+    buffer: [u8; 40],
+    len: usize,
+}
+
+impl fmt::Write for Digits {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let end = self.len + text.len();
+        if end > self.buffer.len() {
+            return Err(fmt::Error);
+        }
+        self.buffer[self.len..end].copy_from_slice(text.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Splits a float into the digits and decimal places of the shortest decimal that rounds to it.
+///
+/// Returns `None` if the float has too many digits to fit in an `i128`.
+fn decimal_digits(value: f64) -> Option<(i128, u32)> {
+    use fmt::Write;
+
+    let mut digits = Digits {
+        buffer: [0; 40],
+        len: 0,
+    };
+    write!(digits, "{}", value).ok()?;
+    let digits = core::str::from_utf8(&digits.buffer[..digits.len]).ok()?;
+    parse_decimal(digits)
+}
+
+/// Multiplies or divides a value by a conversion factor.
+///
+/// Factors below one that the scalar can't hold exactly are applied the other way as their reciprocal,
+/// which scalars with a fixed number of fractional digits hold more precisely.
+/// Factors too large for the scalar to hold are applied as exact fractions, see [`scale_large`].
+/// Scalars that hold more digits than an `f64` are given the factor as it's written.
+#[inline]
+fn scale<S: Scalar>(value: S, by: __Factor, multiply: bool) -> Result<S, ConversionError> {
+    let scalar = S::from_f64(by.value);
+    let exact = is_wide::<S>()
+        || scalar
+            .clone()
+            .and_then(|scalar| scalar.to_f64())
+            .map(f64::to_bits)
+            == Some(by.value.to_bits());
+    // Positive floats are ordered the same way as their bits.
+    if !exact && by.value.to_bits() < 1.0f64.to_bits() {
+        return match S::from_f64(by.reciprocal) {
+            Some(reciprocal) => Ok(if multiply {
+                value / reciprocal
+            } else {
+                value * reciprocal
+            }),
+            None => scale_large(value, by, multiply),
+        };
+    }
+    if scalar.is_none() {
+        return scale_large(value, by, multiply);
+    }
+
+    let by = factor::<S>(by)?;
+    Ok(if multiply { value * by } else { value / by })
+}
+
+/// Multiplies or divides a value by a factor the scalar can't hold, like the `1e9` of micrograms
+/// or the reciprocal of the `0.00064516` of square inches in a 16 bit fixed point number.
+///
+/// The value is expanded into an exact fraction, in digits of the largest power of ten the scalar can hold,
+/// and multiplied by the factor as it's written with integer arithmetic. The result is converted back the same way,
+/// so results the scalar can hold keep its precision, and results it can't hold fail instead of overflowing.
+fn scale_large<S: Scalar>(value: S, by: __Factor, multiply: bool) -> Result<S, ConversionError> {
+    let (mantissa, scale) = parse_decimal(by.text).ok_or(ConversionError::NotRepresentable)?;
+    let power = 10i128
+        .checked_pow(scale)
+        .ok_or(ConversionError::NotRepresentable)?;
+    let by = if multiply {
+        Fraction::new(mantissa, power)
+    } else {
+        Fraction::new(power, mantissa)
+    }
+    .ok_or(ConversionError::NotRepresentable)?;
+
+    let mut chunk: i128 = 10;
+    if S::from_i128(chunk).is_none() {
+        return Err(ConversionError::NotRepresentable);
+    }
+    while chunk < 1_000_000_000_000_000_000 && S::from_i128(chunk * 10).is_some() {
+        chunk *= 10;
+    }
+    let step = S::from_i128(chunk).ok_or(ConversionError::NotRepresentable)?;
+
+    let whole = value.to_i128().ok_or(ConversionError::Overflow)?;
+    let mut rest = value - S::from_i128(whole).ok_or(ConversionError::Overflow)?;
+    let mut expanded: (i128, i128) = (whole, 1);
+    let mut product = Fraction::new(whole, 1)
+        .and_then(|whole| whole.mul(by))
+        .ok_or(ConversionError::Overflow)?;
+    // Fractions of fixed point numbers end after a digit for each fractional bit,
+    // so this stops once the value is exact, or when the fraction would overflow.
+    while !is_zero(&rest) {
+        rest = rest * step.clone();
+        let digit = rest.to_i128().ok_or(ConversionError::NotRepresentable)?;
+        rest = rest - S::from_i128(digit).ok_or(ConversionError::NotRepresentable)?;
+
+        let numerator = expanded.0.checked_mul(chunk).and_then(|value| value.checked_add(digit));
+        let next = match (numerator, expanded.1.checked_mul(chunk)) {
+            (Some(numerator), Some(denominator)) => (numerator, denominator),
+            _ => break,
+        };
+        match Fraction::new(next.0, next.1).and_then(|value| value.mul(by)) {
+            Some(next_product) => {
+                expanded = next;
+                product = next_product;
+            }
+            None => break,
+        }
+    }
+
+    let whole = product.numerator / product.denominator;
+    let mut remainder = product.numerator % product.denominator;
+    let result = S::from_i128(whole).ok_or(ConversionError::Overflow)?;
+    // A negative fraction takes the result below `whole`, which has to fit too.
+    if remainder < 0 && S::from_i128(whole - 1).is_none() {
+        return Err(ConversionError::Overflow);
+    }
+
+    let mut digits = [0i128; 24];
+    let mut len = 0;
+    while len < digits.len() && remainder != 0 {
+        remainder = match remainder.checked_mul(chunk) {
+            Some(remainder) => remainder,
+            None => break,
+        };
+        digits[len] = remainder / product.denominator;
+        remainder %= product.denominator;
+        len += 1;
+    }
+    // Adding the digits from the last one keeps every step within one `chunk`.
+    let mut fraction = S::from_i128(0).ok_or(ConversionError::NotRepresentable)?;
+    for &digit in digits[..len].iter().rev() {
+        fraction = (S::from_i128(digit).ok_or(ConversionError::NotRepresentable)? + fraction) / step.clone();
+    }
+    Ok(result + fraction)
+}
+
+/// Converts a value between two units given by their symbols or names.
+///
+/// Units are looked up in the dimensions provided by Shrewnit.
+/// To also convert between units of custom dimensions, use [`convert_in`].
+///
+/// ```
+/// use shrewnit::units::UnitNameError;
+///
+/// assert_eq!(shrewnit::convert(5.0, "ft", "in"), Ok(60.0));
+/// assert_eq!(shrewnit::convert(212.0, "°F", "degrees Celsius"), Ok(100.0));
+///
+/// assert_eq!(shrewnit::convert(5.0, "ft", "furlongs"), Err(UnitNameError::UnknownUnit));
+/// assert_eq!(
+///     shrewnit::convert(5.0, "ft", "kg"),
+///     Err(UnitNameError::WrongDimension { expected: "Length", found: "Mass" })
+/// );
+/// ```
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, UnitNameError> {
+    convert_in(value, from, to, &[])
+}
+
+/// Converts a value between two units given by their symbols or names,
+/// looking them up in the given dimensions before the ones provided by Shrewnit.
+///
+/// ```
+/// use shrewnit::{dimension, units::convert_in};
+///
+/// dimension!(
+///     pub Information {
+///         canonical: Bits,
+///
+///         Bits ["b", "bit", "bits"]: 1.0 per canonical,
+///         Bytes ["B", "byte", "bytes"]: per 8.0 canonical,
+///     }
+/// );
+///
+/// let dimensions = [Information::<f64>::UNIT_TABLE];
+/// assert_eq!(convert_in(2.0, "B", "bits", &dimensions), Ok(16.0));
+/// assert_eq!(convert_in(5.0, "ft", "in", &dimensions), Ok(60.0));
+/// ```
+pub fn convert_in(
+    value: f64,
+    from: &str,
+    to: &str,
+    dimensions: &[UnitTable],
+) -> Result<f64, UnitNameError> {
+    let tables = || dimensions.iter().chain(crate::dimensions::BUILTIN_UNITS);
+    let table = tables()
+        .find(|table| table.units.iter().any(|unit| unit.is_symbol(from)))
+        .or_else(|| tables().find(|table| table.units.iter().any(|unit| unit.is_name(from))))
+        .ok_or(UnitNameError::UnknownUnit)?;
+    (table.convert)(value, from, to)
+}
+
+/// Finds the index of the unit written as `text` in `units`.
+///
+/// Symbols take precedence over names.
+/// Empty text only matches a unit of this dimension without a symbol, like [`Unity`](crate::Unity),
+/// so a bare number isn't taken as a ratio when parsing other dimensions.
+#[doc(hidden)]
+pub fn __find_unit(
+    units: &[UnitDescriptor],
+    dimension: &'static str,
+    text: &str,
+) -> Result<usize, UnitNameError> {
+    if text.is_empty() {
+        return units
+            .iter()
+            .position(|unit| unit.symbol.is_empty())
+            .ok_or(UnitNameError::MissingUnit);
+    }
+    if let Some(index) = units.iter().position(|unit| unit.is_symbol(text)) {
+        return Ok(index);
+    }
+    if let Some(index) = units.iter().position(|unit| unit.is_name(text)) {
+        return Ok(index);
+    }
+
+    crate::dimensions::BUILTIN_UNITS
+        .iter()
+        .find(|table| {
+            table
+                .units
+                .iter()
+                .any(|unit| unit.is_symbol(text) || unit.is_name(text))
+        })
+        .map_or(Err(UnitNameError::UnknownUnit), |table| {
+            Err(UnitNameError::WrongDimension {
+                expected: dimension,
+                found: table.dimension,
+            })
+        })
+}
+
+/// Finds the index of the unit in `units` that gives `canonical` the most readable magnitude.
+///
+/// Only linear units of `system` are considered. If the dimension doesn't tag any units with `system`,
+/// all linear units tagged with a system are used instead, and if it doesn't tag any units at all, all linear units are.
+/// The largest unit in which the magnitude is at least one wins,
+/// falling back to the smallest unit for values smaller than one of every unit.
+#[doc(hidden)]
+pub fn __humanize_unit(units: &[UnitDescriptor], canonical: f64, system: UnitSystem) -> usize {
+    let rank = |unit: &UnitDescriptor| {
+        if unit.systems.contains(&system) {
+            2
+        } else if !unit.systems.is_empty() {
+            1
+        } else {
+            0
+        }
+    };
+    let best_rank = units
+        .iter()
+        .filter(|unit| unit.is_linear())
+        .map(rank)
+        .max()
+        .unwrap_or(0);
+    let candidates = || {
+        units
+            .iter()
+            .enumerate()
+            .filter(move |(_, unit)| unit.is_linear() && rank(unit) == best_rank)
+            .map(|(index, unit)| (index, unit.factor.unwrap_or(1.0)))
+    };
+
+    let magnitude = if canonical < 0.0 {
+        -canonical
+    } else {
+        canonical
+    };
+    if !(magnitude > 0.0 && magnitude <= core::f64::MAX) {
+        // Zero, infinities and NaN are just as readable in any unit, so prefer the canonical one.
+        return candidates()
+            .find(|&(_, factor)| factor == 1.0)
+            .or_else(|| candidates().next())
+            .map_or(0, |(index, _)| index);
+    }
+
+    let mut largest_fitting: Option<(usize, f64)> = None;
+    let mut smallest: Option<(usize, f64)> = None;
+    for (index, factor) in candidates() {
+        // Allow for rounding errors, so exactly one kilometer isn't shown as 1000 meters.
+        if magnitude / factor >= 1.0 - 1e-9
+            && largest_fitting.map_or(true, |(_, best)| factor > best)
+        {
+            largest_fitting = Some((index, factor));
+        }
+        if smallest.map_or(true, |(_, best)| factor < best) {
+            smallest = Some((index, factor));
+        }
+    }
+    largest_fitting.or(smallest).map_or(0, |(index, _)| index)
+}
+
+/// Converts a value between two units, failing if the result can't be represented exactly by `S`.
+///
+/// Scalars that can hold fractions accept any result they can hold.
+/// Other scalars, like integers, are converted with exact fractions of their factors and offsets,
+/// and only accept whole results that fit.
+#[doc(hidden)]
+pub fn __convert_exact<S, D, U, V>(value: S) -> Option<S>
+where
+    S: Scalar,
+    D: Dimension<S>,
+    U: TryUnitOf<S, D> + UnitInfo,
+    V: TryUnitOf<S, D> + UnitInfo,
+{
+    if is_fractional::<S>() {
+        return __convert_rounded::<S, D, U, V>(value).ok();
+    }
+
+    let converted = convert_fraction(value, UnitDescriptor::of::<U>(), UnitDescriptor::of::<V>())?;
+    if converted.denominator != 1 {
+        return None;
+    }
+    S::from_i128(converted.numerator)
+}
+
+/// Converts a value between two units, rounding the result to `S` like other conversions do.
+///
+/// Scalars that can hold fractions are converted through the canonical unit.
+/// Other scalars, like integers, are converted with exact fractions of their factors and offsets,
+/// and the result is truncated.
+#[doc(hidden)]
+pub fn __convert_rounded<S, D, U, V>(value: S) -> Result<S, ConversionError>
+where
+    S: Scalar,
+    D: Dimension<S>,
+    U: TryUnitOf<S, D> + UnitInfo,
+    V: TryUnitOf<S, D> + UnitInfo,
+{
+    if is_fractional::<S>() {
+        return V::try_from_canonical(U::try_to_canonical(value)?);
+    }
+
+    let converted = convert_fraction(value, UnitDescriptor::of::<U>(), UnitDescriptor::of::<V>())
+        .ok_or(ConversionError::Overflow)?;
+    S::from_i128(converted.numerator / converted.denominator).ok_or(ConversionError::Overflow)
+}
+
+/// Converts a whole value between two units as an exact fraction.
+fn convert_fraction<S: Scalar>(value: S, from: UnitDescriptor, to: UnitDescriptor) -> Option<Fraction> {
+    let value = Fraction::new(value.to_i128()?, 1)?;
+    let canonical = value
+        .mul(Fraction::of(from.factor?)?)?
+        .add(Fraction::of(from.offset)?)?;
+    canonical
+        .add(Fraction::of(-to.offset)?)?
+        .div(Fraction::of(to.factor?)?)
+}
+
+/// An exact fraction in lowest terms, with a positive denominator.
+///
+/// Every operation returns `None` if the result overflows an `i128`.
+#[derive(Clone, Copy)]
+struct Fraction {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Fraction {
+    fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+        Some(Self {
+            numerator: numerator.checked_div(divisor)?.checked_mul(sign)?,
+            denominator: denominator.checked_div(divisor)?.checked_mul(sign)?,
+        })
+    }
+
+    /// Converts a factor or offset to the fraction it's written as.
+    ///
+    /// Factors are written either as a decimal or as the reciprocal of one, like `1.8 per canonical`.
+    /// Decimals have at most 15 significant digits, which an `f64` always keeps,
+    /// so a float with more digits is taken as the reciprocal of a decimal, or else as the simplest fraction rounding to it.
+    fn of(value: f64) -> Option<Self> {
+        let (mantissa, scale) = decimal_digits(value)?;
+        let decimal = Self::new(mantissa, 10i128.checked_pow(scale)?)?;
+        if value == 0.0 || digits(mantissa) <= 15 {
+            return Some(decimal);
+        }
+        if let Some((mantissa, scale)) = decimal_digits(1.0 / value) {
+            if digits(mantissa) <= 15 {
+                return Self::new(10i128.checked_pow(scale)?, mantissa);
+            }
+        }
+        Some(Self::simplest(value).unwrap_or(decimal))
+    }
+
+    /// Finds the fraction with the smallest denominator, up to a million, that rounds to `value`.
+    fn simplest(value: f64) -> Option<Self> {
+        let magnitude = if value < 0.0 { -value } else { value };
+        let mut rest = magnitude;
+        let (mut numerators, mut denominators) = ((0i128, 1i128), (1i128, 0i128));
+        // Each step takes the next term of the continued fraction of `value`.
+        while rest < 1e18 {
+            let term = rest as i128;
+            let numerator = term.checked_mul(numerators.1)?.checked_add(numerators.0)?;
+            let denominator = term.checked_mul(denominators.1)?.checked_add(denominators.0)?;
+            if denominator > 1_000_000 {
+                return None;
+            }
+            if numerator as f64 / denominator as f64 == magnitude {
+                let numerator = if value < 0.0 { -numerator } else { numerator };
+                return Self::new(numerator, denominator);
+            }
+            numerators = (numerators.1, numerator);
+            denominators = (denominators.1, denominator);
+
+            let fraction = rest - term as f64;
+            if fraction == 0.0 {
+                return None;
+            }
+            rest = 1.0 / fraction;
+        }
+        None
+    }
+
+    fn add(self, rhs: Self) -> Option<Self> {
+        Self::new(
+            self.numerator
+                .checked_mul(rhs.denominator)?
+                .checked_add(rhs.numerator.checked_mul(self.denominator)?)?,
+            self.denominator.checked_mul(rhs.denominator)?,
+        )
+    }
+
+    fn mul(self, rhs: Self) -> Option<Self> {
+        // Cancel across the fractions first, so the products overflow as late as possible.
+        let left = gcd(self.numerator, rhs.denominator);
+        let right = gcd(rhs.numerator, self.denominator);
+        Self::new(
+            (self.numerator / left).checked_mul(rhs.numerator / right)?,
+            (self.denominator / right).checked_mul(rhs.denominator / left)?,
+        )
+    }
+
+    fn div(self, rhs: Self) -> Option<Self> {
+        self.mul(Self::new(rhs.denominator, rhs.numerator)?)
+    }
+}
+
+/// Finds the greatest common divisor of two numbers, which is never zero.
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (magnitude(a), magnitude(b));
+    while b != 0 {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    // The divisor of `i128::MIN` and zero doesn't fit in an `i128`, so fall back to not reducing.
+    if a == 0 || a > core::i128::MAX as u128 {
+        1
+    } else {
+        a as i128
+    }
+}
+
+/// Counts the digits of a number.
+fn digits(value: i128) -> u32 {
+    let mut value = magnitude(value);
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Returns the absolute value of a number, which always fits in a `u128`.
+fn magnitude(value: i128) -> u128 {
+    if value < 0 {
+        (value as u128).wrapping_neg()
+    } else {
+        value as u128
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_system {
+    (metric) => {
+        $crate::units::UnitSystem::Metric
+    };
+    (imperial) => {
+        $crate::units::UnitSystem::Imperial
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unit_info_imp {
+    ($unit:ident [$symbol:literal, $name:literal, $plural:literal $(, $alias:literal)*] $(in [$($system:ident),*])? $(=> $factor:expr, $offset:expr)?) => {
+        #[allow(clippy::excessive_precision)]
+        impl $crate::units::UnitInfo for $unit {
+            const SYMBOL: &'static str = $symbol;
+            const NAME: &'static str = $name;
+            const PLURAL: &'static str = $plural;
+            const ALIASES: &'static [&'static str] = &[$($alias),*];
+            $(
+                const FACTOR: Option<f64> = Some($factor);
+                const OFFSET: f64 = $offset;
+            )?
+            $(
+                const SYSTEMS: &'static [$crate::units::UnitSystem] = &[$($crate::__unit_system!($system)),*];
+            )?
+        }
+    };
+    ($unit:ident $(in [$($system:ident),*])? $(=> $factor:expr, $offset:expr)?) => {
+        #[allow(clippy::excessive_precision)]
+        impl $crate::units::UnitInfo for $unit {
+            const SYMBOL: &'static str = stringify!($unit);
+            $(
+                const FACTOR: Option<f64> = Some($factor);
+                const OFFSET: f64 = $offset;
+            )?
+            $(
+                const SYSTEMS: &'static [$crate::units::UnitSystem] = &[$($crate::__unit_system!($system)),*];
+            )?
+        }
+    };
+}